@@ -0,0 +1,28 @@
+//! Emits a `subatomic_has_<width>` cfg for every atomic width the enabled backend provides on the target,
+//! so the portable-atomic, loom and native gating of each width is written once, here, instead of at every use.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let portable_atomic = env::var_os("CARGO_FEATURE_PORTABLE_ATOMIC").is_some();
+    let loom = env::var_os("CARGO_FEATURE_LOOM").is_some();
+    let native = env::var("CARGO_CFG_TARGET_HAS_ATOMIC").unwrap_or_default();
+    let native = |width: &str| native.split(',').any(|has| has == width);
+
+    // portable-atomic provides every width and loom every width but 128, falling back to the native atomics otherwise.
+    let has = |width: &str| portable_atomic || (loom && width != "128") || native(width);
+    for width in ["8", "16", "32", "64", "128", "ptr"] {
+        emit(&format!("subatomic_has_{width}"), has(width));
+    }
+    // The 1, 2 and 4-byte widths that `Subatomic` picks from, required by it and the types built on it.
+    emit("subatomic_has_dynamic", has("8") && has("16") && has("32"));
+}
+
+fn emit(cfg: &str, enabled: bool) {
+    println!("cargo:rustc-check-cfg=cfg({cfg})");
+    if enabled {
+        println!("cargo:rustc-cfg={cfg}");
+    }
+}
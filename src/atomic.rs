@@ -0,0 +1,31 @@
+//! Backing atomics, swapped for loom's model-checked atomics when the `loom` feature is enabled,
+//! or for `portable-atomic`'s atomics, which also work on targets without native atomics, when the `portable-atomic` feature is enabled.
+#![allow(unused_imports)]
+
+#[cfg(not(feature = "loom"))]
+pub(crate) use core::{hint::spin_loop, sync::atomic::fence};
+#[cfg(feature = "loom")]
+pub(crate) use loom::{hint::spin_loop, sync::atomic::fence};
+
+#[cfg(all(
+    not(any(feature = "loom", feature = "portable-atomic")),
+    subatomic_has_64
+))]
+pub(crate) use core::sync::atomic::AtomicU64;
+#[cfg(not(any(feature = "loom", feature = "portable-atomic")))]
+pub(crate) use core::sync::atomic::{
+    AtomicBool, AtomicPtr, AtomicU16, AtomicU32, AtomicU8, AtomicUsize,
+};
+#[cfg(feature = "loom")]
+pub(crate) use loom::sync::atomic::{
+    AtomicBool, AtomicPtr, AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize,
+};
+#[cfg(all(feature = "portable-atomic", not(feature = "loom")))]
+pub(crate) use portable_atomic::{
+    AtomicBool, AtomicPtr, AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize,
+};
+
+#[cfg(all(feature = "u128", not(feature = "portable-atomic"), subatomic_has_128))]
+pub(crate) use core::sync::atomic::AtomicU128;
+#[cfg(all(feature = "u128", feature = "portable-atomic"))]
+pub(crate) use portable_atomic::AtomicU128;
@@ -0,0 +1,402 @@
+//! The backing atomic integers of the wrappers, behind a common trait.
+
+use core::{
+    fmt,
+    ops::{BitAnd, Not, Shl},
+    sync::atomic::Ordering,
+};
+
+#[cfg(all(feature = "u128", subatomic_has_128))]
+use crate::atomic::AtomicU128;
+#[cfg(subatomic_has_16)]
+use crate::atomic::AtomicU16;
+#[cfg(subatomic_has_32)]
+use crate::atomic::AtomicU32;
+#[cfg(subatomic_has_64)]
+use crate::atomic::AtomicU64;
+#[cfg(subatomic_has_8)]
+use crate::atomic::AtomicU8;
+use crate::BitOps;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An atomic integer type that can back a `Subatomic` or `SubatomicN`, exposing the integer it stores as `Bits`.
+/// This trait is sealed and implemented for the 1, 2, 4, 8 and 16-byte atomic integers.
+pub trait AtomicBacking: sealed::Sealed {
+    /// The integer type stored by the atomic.
+    type Bits: BitOps
+        + Eq
+        + Ord
+        + fmt::Debug
+        + fmt::LowerHex
+        + BitAnd<Output = Self::Bits>
+        + Not<Output = Self::Bits>
+        + Shl<u32, Output = Self::Bits>;
+
+    /// Whether operations on the atomic are lock-free on every CPU this build can run on.
+    const IS_ALWAYS_LOCK_FREE: bool;
+
+    /// Creates a new atomic holding `bits`.
+    fn new(bits: Self::Bits) -> Self;
+
+    /// Loads the stored integer.
+    fn load(&self, ordering: Ordering) -> Self::Bits;
+
+    /// Stores `bits`.
+    fn store(&self, bits: Self::Bits, ordering: Ordering);
+
+    /// Stores `bits`, returning the previous integer.
+    fn swap(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Stores `new` if the stored integer is `current`, returning the previous integer.
+    fn compare_exchange(
+        &self,
+        current: Self::Bits,
+        new: Self::Bits,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::Bits, Self::Bits>;
+
+    /// Same as `compare_exchange`, but allowed to fail spuriously.
+    fn compare_exchange_weak(
+        &self,
+        current: Self::Bits,
+        new: Self::Bits,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::Bits, Self::Bits>;
+
+    /// Wrapping addition, returning the previous integer.
+    fn fetch_add(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Wrapping subtraction, returning the previous integer.
+    fn fetch_sub(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Bitwise "and", returning the previous integer.
+    fn fetch_and(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Bitwise "nand", returning the previous integer.
+    fn fetch_nand(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Bitwise "or", returning the previous integer.
+    fn fetch_or(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Bitwise "xor", returning the previous integer.
+    fn fetch_xor(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Unsigned maximum, returning the previous integer.
+    fn fetch_max(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Unsigned minimum, returning the previous integer.
+    fn fetch_min(&self, bits: Self::Bits, ordering: Ordering) -> Self::Bits;
+
+    /// Consumes the atomic, returning the stored integer.
+    fn into_inner(self) -> Self::Bits;
+
+    /// Runs `f` on the stored integer through exclusive access.
+    fn with_mut<R, F: FnOnce(&mut Self::Bits) -> R>(&mut self, f: F) -> R;
+
+    /// Returns a mutable reference to the stored integer through exclusive access.
+    #[cfg(not(feature = "loom"))]
+    fn get_mut(&mut self) -> &mut Self::Bits;
+
+    /// Returns a mutable pointer to the stored integer.
+    #[cfg(not(feature = "loom"))]
+    fn as_ptr(&self) -> *mut Self::Bits;
+
+    /// Returns whether operations on the atomic are lock-free on the running CPU.
+    fn is_lock_free() -> bool;
+}
+
+/// Forwards the inherent operations shared by every backend, for use in a module that does not import `AtomicBacking`.
+/// Inside the trait impl, a call such as `<$atomic>::swap(self, ..)` falls back to the trait method itself when the inherent method is missing,
+/// recursing forever; out of the trait's scope, a missing inherent method is a compile error instead.
+#[allow(unused_macros)]
+macro_rules! inherent_common {
+    ($atomic:ident => $bits:ty) => {
+        pub(super) fn new(bits: $bits) -> super::$atomic {
+            super::$atomic::new(bits)
+        }
+
+        pub(super) fn load(atomic: &super::$atomic, ordering: Ordering) -> $bits {
+            atomic.load(ordering)
+        }
+
+        pub(super) fn store(atomic: &super::$atomic, bits: $bits, ordering: Ordering) {
+            atomic.store(bits, ordering)
+        }
+
+        pub(super) fn swap(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.swap(bits, ordering)
+        }
+
+        pub(super) fn compare_exchange(
+            atomic: &super::$atomic,
+            current: $bits,
+            new: $bits,
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<$bits, $bits> {
+            atomic.compare_exchange(current, new, success, failure)
+        }
+
+        pub(super) fn compare_exchange_weak(
+            atomic: &super::$atomic,
+            current: $bits,
+            new: $bits,
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<$bits, $bits> {
+            atomic.compare_exchange_weak(current, new, success, failure)
+        }
+
+        pub(super) fn fetch_add(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.fetch_add(bits, ordering)
+        }
+
+        pub(super) fn fetch_sub(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.fetch_sub(bits, ordering)
+        }
+
+        pub(super) fn fetch_and(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.fetch_and(bits, ordering)
+        }
+
+        pub(super) fn fetch_nand(
+            atomic: &super::$atomic,
+            bits: $bits,
+            ordering: Ordering,
+        ) -> $bits {
+            atomic.fetch_nand(bits, ordering)
+        }
+
+        pub(super) fn fetch_or(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.fetch_or(bits, ordering)
+        }
+
+        pub(super) fn fetch_xor(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.fetch_xor(bits, ordering)
+        }
+
+        pub(super) fn fetch_max(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.fetch_max(bits, ordering)
+        }
+
+        pub(super) fn fetch_min(atomic: &super::$atomic, bits: $bits, ordering: Ordering) -> $bits {
+            atomic.fetch_min(bits, ordering)
+        }
+
+        pub(super) fn into_inner(atomic: super::$atomic) -> $bits {
+            atomic.into_inner()
+        }
+    };
+}
+
+/// Implements the operations shared by every backend through the `inherent` module generated next to the impl.
+#[allow(unused_macros)]
+macro_rules! impl_atomic_backing_common {
+    ($bits:ty) => {
+        fn new(bits: $bits) -> Self {
+            inherent::new(bits)
+        }
+
+        fn load(&self, ordering: Ordering) -> $bits {
+            inherent::load(self, ordering)
+        }
+
+        fn store(&self, bits: $bits, ordering: Ordering) {
+            inherent::store(self, bits, ordering)
+        }
+
+        fn swap(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::swap(self, bits, ordering)
+        }
+
+        fn compare_exchange(
+            &self,
+            current: $bits,
+            new: $bits,
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<$bits, $bits> {
+            inherent::compare_exchange(self, current, new, success, failure)
+        }
+
+        fn compare_exchange_weak(
+            &self,
+            current: $bits,
+            new: $bits,
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<$bits, $bits> {
+            inherent::compare_exchange_weak(self, current, new, success, failure)
+        }
+
+        fn fetch_add(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_add(self, bits, ordering)
+        }
+
+        fn fetch_sub(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_sub(self, bits, ordering)
+        }
+
+        fn fetch_and(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_and(self, bits, ordering)
+        }
+
+        fn fetch_nand(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_nand(self, bits, ordering)
+        }
+
+        fn fetch_or(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_or(self, bits, ordering)
+        }
+
+        fn fetch_xor(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_xor(self, bits, ordering)
+        }
+
+        fn fetch_max(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_max(self, bits, ordering)
+        }
+
+        fn fetch_min(&self, bits: $bits, ordering: Ordering) -> $bits {
+            inherent::fetch_min(self, bits, ordering)
+        }
+
+        fn into_inner(self) -> $bits {
+            inherent::into_inner(self)
+        }
+    };
+}
+
+/// Implements `AtomicBacking` for atomics that can be borrowed mutably, i.e. those of `core` and `portable-atomic`.
+#[allow(unused_macros)]
+macro_rules! impl_atomic_backing {
+    ($($atomic:ident => $bits:ty),*) => {
+        $(
+            const _: () = {
+                // The wrappers are as aligned as their backing atomic, so this guarantees natural alignment on every target.
+                assert!(
+                    core::mem::align_of::<$atomic>() >= core::mem::size_of::<$bits>(),
+                    concat!(stringify!($atomic), " is not naturally aligned on this target")
+                );
+            };
+
+            const _: () = {
+                mod inherent {
+                    use core::sync::atomic::Ordering;
+
+                    inherent_common!($atomic => $bits);
+
+                    pub(super) fn get_mut(atomic: &mut super::$atomic) -> &mut $bits {
+                        atomic.get_mut()
+                    }
+
+                    #[cfg(not(feature = "loom"))]
+                    pub(super) fn as_ptr(atomic: &super::$atomic) -> *mut $bits {
+                        atomic.as_ptr()
+                    }
+
+                    #[cfg(feature = "portable-atomic")]
+                    pub(super) fn is_lock_free() -> bool {
+                        super::$atomic::is_lock_free()
+                    }
+                }
+
+                impl sealed::Sealed for $atomic {}
+
+                impl AtomicBacking for $atomic {
+                    type Bits = $bits;
+
+                    #[cfg(feature = "portable-atomic")]
+                    const IS_ALWAYS_LOCK_FREE: bool = <$atomic>::is_always_lock_free();
+                    #[cfg(not(feature = "portable-atomic"))]
+                    const IS_ALWAYS_LOCK_FREE: bool = true;
+
+                    impl_atomic_backing_common!($bits);
+
+                    fn with_mut<R, F: FnOnce(&mut $bits) -> R>(&mut self, f: F) -> R {
+                        f(inherent::get_mut(self))
+                    }
+
+                    #[cfg(not(feature = "loom"))]
+                    fn get_mut(&mut self) -> &mut $bits {
+                        inherent::get_mut(self)
+                    }
+
+                    #[cfg(not(feature = "loom"))]
+                    fn as_ptr(&self) -> *mut $bits {
+                        inherent::as_ptr(self)
+                    }
+
+                    #[cfg(feature = "portable-atomic")]
+                    fn is_lock_free() -> bool {
+                        inherent::is_lock_free()
+                    }
+
+                    #[cfg(not(feature = "portable-atomic"))]
+                    fn is_lock_free() -> bool {
+                        true
+                    }
+                }
+            };
+        )*
+    };
+}
+
+/// Implements `AtomicBacking` for loom's model-checked atomics, which are only accessed mutably through `with_mut`.
+#[cfg(feature = "loom")]
+macro_rules! impl_loom_atomic_backing {
+    ($($atomic:ident => $bits:ty),*) => {
+        $(
+            const _: () = {
+                mod inherent {
+                    use core::sync::atomic::Ordering;
+
+                    inherent_common!($atomic => $bits);
+
+                    pub(super) fn with_mut<R, F: FnOnce(&mut $bits) -> R>(
+                        atomic: &mut super::$atomic,
+                        f: F,
+                    ) -> R {
+                        atomic.with_mut(f)
+                    }
+                }
+
+                impl sealed::Sealed for $atomic {}
+
+                impl AtomicBacking for $atomic {
+                    type Bits = $bits;
+
+                    const IS_ALWAYS_LOCK_FREE: bool = true;
+
+                    impl_atomic_backing_common!($bits);
+
+                    fn with_mut<R, F: FnOnce(&mut $bits) -> R>(&mut self, f: F) -> R {
+                        inherent::with_mut(self, f)
+                    }
+
+                    fn is_lock_free() -> bool {
+                        true
+                    }
+                }
+            };
+        )*
+    };
+}
+
+#[cfg(all(not(feature = "loom"), subatomic_has_8))]
+impl_atomic_backing!(AtomicU8 => u8);
+#[cfg(all(not(feature = "loom"), subatomic_has_16))]
+impl_atomic_backing!(AtomicU16 => u16);
+#[cfg(all(not(feature = "loom"), subatomic_has_32))]
+impl_atomic_backing!(AtomicU32 => u32);
+#[cfg(all(not(feature = "loom"), subatomic_has_64))]
+impl_atomic_backing!(AtomicU64 => u64);
+#[cfg(feature = "loom")]
+impl_loom_atomic_backing!(AtomicU8 => u8, AtomicU16 => u16, AtomicU32 => u32, AtomicU64 => u64);
+#[cfg(all(feature = "u128", subatomic_has_128))]
+impl_atomic_backing!(AtomicU128 => u128);
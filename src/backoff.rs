@@ -0,0 +1,41 @@
+//! Backoff between failed compare-exchange attempts in the retry loops, with the `backoff` feature.
+//! The n-th consecutive failure spins for 2^n `spin_loop` hints, starting at one and capped at 64,
+//! which spreads out contending threads and improves throughput under heavy contention.
+//! Without the feature, `Backoff` does nothing and the loops retry immediately.
+#![allow(dead_code)]
+
+#[cfg(feature = "backoff")]
+use crate::atomic::spin_loop;
+
+/// Spins scale to `1 << MAX_STEP` hints at most.
+#[cfg(feature = "backoff")]
+const MAX_STEP: u32 = 6;
+
+/// Backoff state for one retry loop.
+pub(crate) struct Backoff {
+    #[cfg(feature = "backoff")]
+    step: u32,
+}
+
+impl Backoff {
+    pub(crate) fn new() -> Self {
+        Self {
+            #[cfg(feature = "backoff")]
+            step: 0,
+        }
+    }
+
+    /// Waits after a failed compare-exchange, for longer on each consecutive call.
+    #[inline]
+    pub(crate) fn spin(&mut self) {
+        #[cfg(feature = "backoff")]
+        {
+            for _ in 0..1u32 << self.step {
+                spin_loop();
+            }
+            if self.step < MAX_STEP {
+                self.step += 1;
+            }
+        }
+    }
+}
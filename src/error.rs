@@ -0,0 +1,62 @@
+use core::fmt;
+
+/// Error returned when T does not have the size required by a wrapper.
+/// Returned by `try_new`, the runtime counterpart of the size check of `new`, which fails the build after monomorphization
+/// and so is not reported by `cargo check`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeMismatch {
+    /// Size in bytes required by the wrapper.
+    pub expected: usize,
+    /// Size in bytes of T.
+    pub actual: usize,
+}
+
+impl fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a type of {} bytes, found {} bytes",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for SizeMismatch {}
+
+/// Error returned when the backing integer does not hold a valid bit pattern for T.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidBits<B> {
+    /// The rejected bit pattern.
+    pub bits: B,
+}
+
+impl<B: fmt::LowerHex> fmt::Display for InvalidBits<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid bit pattern {:#x}", self.bits)
+    }
+}
+
+impl<B: fmt::Debug + fmt::LowerHex> core::error::Error for InvalidBits<B> {}
+
+/// Error returned by `fetch_update_bounded`, carrying the last value observed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchUpdateError<T> {
+    /// The function returned `None` for this value.
+    Rejected(T),
+    /// Every compare-exchange failed because the value kept changing, the last time to this value.
+    RetriesExceeded(T),
+}
+
+impl<T> fmt::Display for FetchUpdateError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rejected(_) => write!(f, "the update function rejected the current value"),
+            Self::RetriesExceeded(_) => write!(
+                f,
+                "the value kept changing until the retry limit was exceeded"
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for FetchUpdateError<T> {}
@@ -0,0 +1,16 @@
+use core::sync::atomic::Ordering;
+
+/// An atomic fence, for ordering relaxed operations on the atomic wrappers with each other.
+/// Use this when the synchronization must be established between threads, e.g. a Release fence before a relaxed store,
+/// paired with an Acquire fence after the relaxed load that observes it.
+/// With the `loom` feature this is loom's fence, so it takes part in model checking.
+pub fn fence(ordering: Ordering) {
+    crate::atomic::fence(ordering);
+}
+
+/// A compiler-only fence, which keeps the compiler from reordering memory operations across it but emits no instructions.
+/// This is only sufficient when the other side runs on the same thread, such as a signal handler or interrupt;
+/// use `fence` to synchronize with other threads.
+pub fn compiler_fence(ordering: Ordering) {
+    core::sync::atomic::compiler_fence(ordering);
+}
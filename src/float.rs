@@ -0,0 +1,69 @@
+use core::cmp::Ordering as CmpOrdering;
+
+use crate::ordering::DefaultOrdering;
+
+#[cfg(all(feature = "u32", subatomic_has_32))]
+use crate::Subatomic32;
+#[cfg(all(feature = "u64", subatomic_has_64))]
+use crate::Subatomic64;
+
+#[cfg(all(feature = "u32", subatomic_has_32))]
+impl<O: DefaultOrdering> Subatomic32<f32, O> {
+    /// Maximum with the current value under `f32::total_cmp`, returning the previous value.
+    /// NaNs are ordered as by `total_cmp`: positive NaN above infinity and negative NaN below negative infinity,
+    /// and `-0.0` is less than `0.0`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_max(&self, val: f32) -> f32 {
+        match self.fetch_update(|current| {
+            (val.total_cmp(&current) == CmpOrdering::Greater).then_some(val)
+        }) {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+
+    /// Minimum with the current value under `f32::total_cmp`, returning the previous value.
+    /// NaNs and signed zeros are ordered as for `fetch_max`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_min(&self, val: f32) -> f32 {
+        match self
+            .fetch_update(|current| (val.total_cmp(&current) == CmpOrdering::Less).then_some(val))
+        {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+}
+
+#[cfg(all(feature = "u64", subatomic_has_64))]
+impl<O: DefaultOrdering> Subatomic64<f64, O> {
+    /// Maximum with the current value under `f64::total_cmp`, returning the previous value.
+    /// NaNs are ordered as by `total_cmp`: positive NaN above infinity and negative NaN below negative infinity,
+    /// so storing a positive NaN is sticky, and `-0.0` is less than `0.0`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_max(&self, val: f64) -> f64 {
+        match self.fetch_update(|current| {
+            (val.total_cmp(&current) == CmpOrdering::Greater).then_some(val)
+        }) {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+
+    /// Minimum with the current value under `f64::total_cmp`, returning the previous value.
+    /// NaNs and signed zeros are ordered as for `fetch_max`, so storing a negative NaN is sticky.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_min(&self, val: f64) -> f64 {
+        match self
+            .fetch_update(|current| (val.total_cmp(&current) == CmpOrdering::Less).then_some(val))
+        {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+
+    /// Adds to the current value in a compare-exchange loop, returning the previous value.
+    /// The addition follows IEEE 754, so a NaN operand or stored value produces a NaN.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_add(&self, val: f64) -> f64 {
+        match self.fetch_update(|current| Some(current + val)) {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+}
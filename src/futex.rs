@@ -0,0 +1,26 @@
+use core::sync::atomic::AtomicU32;
+
+use crate::{ordering::DefaultOrdering, Subatomic32};
+
+impl<T: Copy + 'static, O: DefaultOrdering> Subatomic32<T, O> {
+    /// The backing integer as a core atomic, as expected by `atomic-wait`.
+    fn futex(&self) -> &AtomicU32 {
+        unsafe { AtomicU32::from_ptr(self.as_ptr().cast::<u32>()) }
+    }
+
+    /// Blocks the current thread while the raw backing integer equals `current_bits`, until woken by `notify_one` or `notify_all`.
+    /// Like the underlying futex, this may also return spuriously, so callers should re-check the value in a loop.
+    pub fn wait(&self, current_bits: u32) {
+        atomic_wait::wait(self.futex(), current_bits);
+    }
+
+    /// Wakes one thread blocked in `wait` on this atomic.
+    pub fn notify_one(&self) {
+        atomic_wait::wake_one(self.futex());
+    }
+
+    /// Wakes all threads blocked in `wait` on this atomic.
+    pub fn notify_all(&self) {
+        atomic_wait::wake_all(self.futex());
+    }
+}
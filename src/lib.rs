@@ -1,12 +1,174 @@
 #![no_std]
-//! AtomicU64/AtomicU32 wrappers for equally sized types implementing Copy
+#![cfg_attr(
+    all(feature = "u128", not(feature = "portable-atomic"), subatomic_has_128),
+    feature(integer_atomics)
+)]
+//! AtomicU64/AtomicU32/AtomicU16/AtomicU8 wrappers for equally sized types implementing Copy
+//!
+//! The crate is `no_std` and does not allocate. Helpers that need an allocator, such as `RcuCell`,
+//! are behind the `alloc` feature, which is not enabled by default.
+//!
+//! With the `backoff` feature, the retry loops in `update`, `fetch_update` and `store_if` back off after each failed
+//! compare-exchange, spinning for 1, 2, 4 and so on up to 64 `spin_loop` hints. Without it they retry immediately.
+//!
+//! Integer wrappers, signed or unsigned, can be drained to zero with `clear`. The generic `clear_bits`, which stores the all-zero
+//! bit pattern of any other T, is only available with the `bytemuck` feature, as it requires T to be `bytemuck::Zeroable`.
+//!
+//! **The `relaxed-default` feature changes memory semantics crate-wide.** It makes Ordering::Relaxed the default ordering
+//! of `Subatomic8` through `Subatomic128` whose type does not name one, used by every constructor except `with_ordering`,
+//! so `load`, `store`, `swap` and the read-modify-write operations without an explicit ordering no longer synchronize with other threads.
+//! Since features are unified across a build, this also applies to every other crate in the dependency graph using these wrappers,
+//! so only enable it for statistics counters and similar values that never publish other memory.
+//! The other types in this crate, which rely on their orderings for correctness, are unaffected: `SeqLock`, `Versioned`
+//! and `ShardedCounter` name the ordering of their wrappers, as should any code whose correctness depends on synchronization.
 
-#[cfg(feature = "u64")]
-mod subatomic64;
-#[cfg(feature = "u64")]
-pub use subatomic64::Subatomic64;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-#[cfg(feature = "u32")]
-mod subatomic32;
-#[cfg(feature = "u32")]
-pub use subatomic32::Subatomic32;
+pub mod ordering;
+
+mod backoff;
+
+mod atomic;
+
+#[macro_use]
+mod trace;
+
+mod backing;
+pub use backing::AtomicBacking;
+
+#[cfg(subatomic_has_dynamic)]
+mod subatomic;
+#[cfg(subatomic_has_dynamic)]
+pub use subatomic::Subatomic;
+
+mod subatomic_n;
+pub use subatomic_n::{Bytes, SubatomicN, SupportedWidth};
+
+#[cfg(feature = "cache-padded")]
+mod padded;
+#[cfg(feature = "cache-padded")]
+pub use padded::CachePadded;
+
+#[cfg(all(feature = "u64", subatomic_has_64))]
+mod subatomic_array;
+#[cfg(all(feature = "u64", subatomic_has_64))]
+pub use subatomic_array::SubatomicArray;
+
+#[cfg(all(feature = "bool", subatomic_has_8))]
+mod subatomic_bool;
+#[cfg(all(feature = "bool", subatomic_has_8))]
+pub use subatomic_bool::SubatomicBool;
+
+#[cfg(subatomic_has_32)]
+mod subatomic_latch;
+#[cfg(subatomic_has_32)]
+pub use subatomic_latch::SubatomicLatch;
+
+#[cfg(subatomic_has_ptr)]
+mod subatomic_ptr;
+#[cfg(subatomic_has_ptr)]
+pub use subatomic_ptr::SubatomicPtr;
+
+#[cfg(subatomic_has_dynamic)]
+mod subatomic_option;
+#[cfg(subatomic_has_dynamic)]
+pub use subatomic_option::SubatomicOption;
+
+mod subatomic_mut;
+pub use subatomic_mut::SubatomicMut;
+
+#[cfg(all(feature = "u64", subatomic_has_64))]
+mod seqlock;
+#[cfg(all(feature = "u64", subatomic_has_64))]
+pub use seqlock::SeqLock;
+
+#[cfg(all(feature = "u64", subatomic_has_dynamic, subatomic_has_64))]
+mod versioned;
+#[cfg(all(feature = "u64", subatomic_has_dynamic, subatomic_has_64))]
+pub use versioned::Versioned;
+
+#[cfg(all(feature = "cache-padded", feature = "u64", subatomic_has_64))]
+mod sharded_counter;
+#[cfg(all(feature = "cache-padded", feature = "u64", subatomic_has_64))]
+pub use sharded_counter::ShardedCounter;
+
+#[cfg(all(feature = "alloc", subatomic_has_ptr))]
+mod rcu;
+#[cfg(all(feature = "alloc", subatomic_has_ptr))]
+pub use rcu::RcuCell;
+
+#[cfg(all(feature = "futex", not(feature = "loom")))]
+mod futex;
+
+mod error;
+pub use error::{FetchUpdateError, InvalidBits, SizeMismatch};
+
+#[cfg(any(
+    all(feature = "u32", subatomic_has_32),
+    all(feature = "u64", subatomic_has_64)
+))]
+mod float;
+
+mod outcome;
+pub use outcome::CasOutcome;
+
+mod raw_bits;
+pub use raw_bits::RawBits;
+
+#[cfg(feature = "serde")]
+pub mod serde_bits;
+
+mod fence;
+pub use fence::{compiler_fence, fence};
+
+mod marker;
+pub use marker::{Arithmetic, BitOps, Niche};
+
+/// Trait bounds required by the `bytemuck`-checked constructors.
+#[cfg(feature = "bytemuck")]
+pub use bytemuck::{NoUninit, Pod};
+
+/// Wraps a 16-byte length T in an atomic, an alias of `SubatomicN<T, 16>`.
+///
+/// The compare-exchange operations are a double-word CAS, e.g. for a `(pointer, tag)` pair that defeats ABA in lock-free structures.
+/// With the `portable-atomic` feature they use `cmpxchg16b` on x86_64, detected at runtime unless enabled at compile time with
+/// `-C target-feature=+cmpxchg16b`, and `casp` or `ldxp`/`stxp` on aarch64, falling back to a global lock on targets without a 128-bit CAS.
+/// Without it, they use the native 128-bit atomics of `core`, which require nightly and `target_has_atomic = "128"`.
+#[cfg(all(feature = "u128", subatomic_has_128))]
+pub type Subatomic128<T, O = ordering::CrateDefault> = SubatomicN<T, 16, O>;
+
+/// Wraps an 8-byte length T in an atomic, an alias of `SubatomicN<T, 8>`.
+#[cfg(all(feature = "u64", subatomic_has_64))]
+pub type Subatomic64<T, O = ordering::CrateDefault> = SubatomicN<T, 8, O>;
+
+/// Wraps a 4-byte length T in an atomic, an alias of `SubatomicN<T, 4>`.
+#[cfg(all(feature = "u32", subatomic_has_32))]
+pub type Subatomic32<T, O = ordering::CrateDefault> = SubatomicN<T, 4, O>;
+
+/// Wraps a 2-byte length T in an atomic, an alias of `SubatomicN<T, 2>`.
+#[cfg(all(feature = "u16", subatomic_has_16))]
+pub type Subatomic16<T, O = ordering::CrateDefault> = SubatomicN<T, 2, O>;
+
+/// Wraps a 1-byte length T in an atomic, an alias of `SubatomicN<T, 1>`.
+#[cfg(all(feature = "u8", subatomic_has_8))]
+pub type Subatomic8<T, O = ordering::CrateDefault> = SubatomicN<T, 1, O>;
+
+#[cfg(all(feature = "u64", not(subatomic_has_64)))]
+compile_error!("the `u64` feature requires 64-bit atomics, which this target lacks: enable the `portable-atomic` feature, or disable `u64`, which is a default feature");
+#[cfg(all(feature = "u32", not(subatomic_has_32)))]
+compile_error!("the `u32` feature requires 32-bit atomics, which this target lacks: enable the `portable-atomic` feature, or disable `u32`, which is a default feature");
+#[cfg(all(feature = "u16", not(subatomic_has_16)))]
+compile_error!("the `u16` feature requires 16-bit atomics, which this target lacks: enable the `portable-atomic` feature, or disable `u16`, which is a default feature");
+#[cfg(all(feature = "u8", not(subatomic_has_8)))]
+compile_error!("the `u8` feature requires 8-bit atomics, which this target lacks: enable the `portable-atomic` feature, or disable `u8`, which is a default feature");
+
+/// Wraps a pointer-sized T in an atomic, backed by the wrapper matching the target's pointer width.
+#[cfg(all(feature = "u64", subatomic_has_64, target_pointer_width = "64"))]
+pub type SubatomicUsize<T, O = ordering::CrateDefault> = Subatomic64<T, O>;
+/// Wraps a pointer-sized T in an atomic, backed by the wrapper matching the target's pointer width.
+#[cfg(all(feature = "u32", subatomic_has_32, target_pointer_width = "32"))]
+pub type SubatomicUsize<T, O = ordering::CrateDefault> = Subatomic32<T, O>;
+/// Wraps a pointer-sized T in an atomic, backed by the wrapper matching the target's pointer width.
+#[cfg(all(feature = "u16", subatomic_has_16, target_pointer_width = "16"))]
+pub type SubatomicUsize<T, O = ordering::CrateDefault> = Subatomic16<T, O>;
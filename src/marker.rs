@@ -0,0 +1,131 @@
+use core::{
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ptr::NonNull,
+};
+
+pub(crate) mod sealed {
+    /// Integer operations needed by the generic arithmetic helpers and `SubatomicN`, forwarding to the inherent methods.
+    pub trait Sealed: Sized {
+        const ZERO: Self;
+        const ONE: Self;
+        const BITS: u32;
+
+        fn wrapping_add(self, rhs: Self) -> Self;
+        fn wrapping_sub(self, rhs: Self) -> Self;
+        fn saturating_add(self, rhs: Self) -> Self;
+        fn saturating_sub(self, rhs: Self) -> Self;
+        fn checked_add(self, rhs: Self) -> Option<Self>;
+        fn checked_sub(self, rhs: Self) -> Option<Self>;
+        fn rotate_left(self, n: u32) -> Self;
+        fn rotate_right(self, n: u32) -> Self;
+        fn to_le(self) -> Self;
+        fn from_le(bits: Self) -> Self;
+    }
+}
+
+/// Marker for the primitive integer types, whose arithmetic on the raw bits matches the wrapping arithmetic of the backing atomic.
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Arithmetic: Copy + sealed::Sealed {}
+
+macro_rules! impl_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const BITS: u32 = <$t>::BITS;
+
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$t>::wrapping_add(self, rhs)
+                }
+
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$t>::wrapping_sub(self, rhs)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$t>::saturating_sub(self, rhs)
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn rotate_left(self, n: u32) -> Self {
+                    <$t>::rotate_left(self, n)
+                }
+
+                fn rotate_right(self, n: u32) -> Self {
+                    <$t>::rotate_right(self, n)
+                }
+
+                fn to_le(self) -> Self {
+                    <$t>::to_le(self)
+                }
+
+                fn from_le(bits: Self) -> Self {
+                    <$t>::from_le(bits)
+                }
+            }
+            impl Arithmetic for $t {}
+        )*
+    };
+}
+
+impl_arithmetic!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Marker for the unsigned primitive integer types, for which bitwise and ordering operations on the raw bits match the operations on the value.
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait BitOps: Arithmetic {}
+
+macro_rules! impl_bit_ops {
+    ($($t:ty),*) => {
+        $(
+            impl BitOps for $t {}
+        )*
+    };
+}
+
+impl_bit_ops!(u8, u16, u32, u64, u128, usize);
+
+/// Types whose `Option` stores `None` in a niche of the type itself, without any uninitialized bytes.
+///
+/// # Safety
+/// `Option<Self>` must have the same size as `Self`, and every byte of both `None` and `Some(_)` must be initialized.
+/// This holds for non-null pointers and nonzero integers, where `None` is the all-zero bit pattern.
+pub unsafe trait Niche: Copy + 'static {}
+
+macro_rules! impl_niche {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl Niche for $t {}
+        )*
+    };
+}
+
+impl_niche!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize
+);
+
+unsafe impl<T: 'static> Niche for NonNull<T> {}
+unsafe impl<T: 'static> Niche for &'static T {}
@@ -0,0 +1,58 @@
+//! Marker types choosing the default ordering of `SubatomicN` and its aliases at the type level,
+//! e.g. `Subatomic64<u64, Relaxed>`, so the wrapper does not need to store it.
+
+use core::sync::atomic::Ordering;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A marker naming the ordering used by the operations of a wrapper that take no explicit ordering.
+/// This trait is sealed and implemented for the markers of this module.
+pub trait DefaultOrdering: sealed::Sealed + 'static {
+    /// The ordering named by the marker.
+    const ORDERING: Ordering;
+}
+
+macro_rules! default_orderings {
+    ($($name:ident),*) => {
+        $(
+            #[doc = concat!("Makes Ordering::", stringify!($name), " the default ordering of a wrapper.")]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            pub struct $name;
+
+            impl sealed::Sealed for $name {}
+
+            impl DefaultOrdering for $name {
+                const ORDERING: Ordering = Ordering::$name;
+            }
+        )*
+    };
+}
+
+default_orderings!(Relaxed, Release, Acquire, AcqRel, SeqCst);
+
+/// The default ordering of the width wrappers when the type does not name one, used by every constructor except `with_ordering`.
+#[cfg(not(feature = "relaxed-default"))]
+pub type CrateDefault = SeqCst;
+/// The default ordering of the width wrappers when the type does not name one, used by every constructor except `with_ordering`.
+#[cfg(feature = "relaxed-default")]
+pub type CrateDefault = Relaxed;
+
+/// Maps a wrapper's default ordering to the closest valid ordering for a load.
+pub(crate) fn load_ordering(ordering: Ordering) -> Ordering {
+    match ordering {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        ordering => ordering,
+    }
+}
+
+/// Maps a wrapper's default ordering to the closest valid ordering for a store.
+pub(crate) fn store_ordering(ordering: Ordering) -> Ordering {
+    match ordering {
+        Ordering::Acquire => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Release,
+        ordering => ordering,
+    }
+}
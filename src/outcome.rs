@@ -0,0 +1,8 @@
+/// The result of a compare-exchange as a plain struct, returned by `compare_exchange_detailed` on the `SubatomicN` types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CasOutcome<T> {
+    /// Whether the new value was written.
+    pub success: bool,
+    /// The value witnessed by the compare-exchange, equal to `current` on success.
+    pub previous: T,
+}
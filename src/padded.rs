@@ -0,0 +1,66 @@
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+/// Pads and aligns a value to 128 bytes, so that adjacent atomics never share a cache line.
+/// 128 bytes covers both the adjacent-line prefetcher of modern x86_64 cores and the 128-byte cache lines of some aarch64 cores,
+/// so `size_of::<CachePadded<Subatomic64<u64>>>()` is 128.
+#[repr(align(128))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CachePadded<A> {
+    value: A,
+}
+
+impl<A> CachePadded<A> {
+    /// Pads and aligns `value` to 128 bytes.
+    pub const fn new(value: A) -> Self {
+        Self { value }
+    }
+
+    /// Consumes the padding, returning the wrapped value.
+    pub fn into_inner(self) -> A {
+        self.value
+    }
+}
+
+impl<A> Deref for CachePadded<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        &self.value
+    }
+}
+
+impl<A> DerefMut for CachePadded<A> {
+    fn deref_mut(&mut self) -> &mut A {
+        &mut self.value
+    }
+}
+
+impl<A> From<A> for CachePadded<A> {
+    fn from(value: A) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<A: fmt::Debug> fmt::Debug for CachePadded<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <A as fmt::Debug>::fmt(&self.value, f)
+    }
+}
+
+#[cfg(all(test, not(feature = "loom"), feature = "u32", feature = "u64"))]
+mod tests {
+    use core::mem::{align_of, size_of};
+
+    use super::CachePadded;
+    use crate::{Subatomic32, Subatomic64};
+
+    #[test]
+    fn pads_to_128_bytes() {
+        assert_eq!(size_of::<CachePadded<Subatomic64<u64>>>(), 128);
+        assert_eq!(align_of::<CachePadded<Subatomic64<u64>>>(), 128);
+        assert_eq!(size_of::<[CachePadded<Subatomic32<u32>>; 2]>(), 256);
+    }
+}
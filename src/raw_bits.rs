@@ -0,0 +1,18 @@
+use core::fmt;
+
+/// The raw backing integer of an atomic wrapper, formatted in hex by Debug and Display regardless of what T is.
+/// Returned by `debug_bits` and `display_bits` on the `SubatomicN` types.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawBits<B>(pub B);
+
+impl<B: fmt::LowerHex> fmt::Debug for RawBits<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl<B: fmt::LowerHex> fmt::Display for RawBits<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::sync::Arc;
+use core::{fmt, marker::PhantomData, sync::atomic::Ordering};
+
+use crate::{
+    atomic::{spin_loop, AtomicUsize},
+    SubatomicPtr,
+};
+
+/// Atomically publishes an `Arc<T>`, letting readers take a snapshot with `load` while writers replace it with `store`.
+///
+/// The cell holds one strong count of the current `Arc`, stored as a raw pointer in a `SubatomicPtr`.
+/// Readers count themselves in flight while cloning the current `Arc`, and writers wait for in-flight readers to finish
+/// before releasing the previous `Arc`, so a reader never increments the count of a freed value.
+/// Reads only take a refcount increment, but a writer can be delayed for as long as reads keep overlapping.
+pub struct RcuCell<T> {
+    ptr: SubatomicPtr<*const ()>,
+    readers: AtomicUsize,
+    _phantom: PhantomData<Arc<T>>,
+}
+
+unsafe impl<T: Send + Sync> Send for RcuCell<T> {}
+unsafe impl<T: Send + Sync> Sync for RcuCell<T> {}
+
+impl<T: Default> Default for RcuCell<T> {
+    fn default() -> Self {
+        Self::new(Arc::new(T::default()))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RcuCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Debug>::fmt(&self.load(), f)
+    }
+}
+
+impl<T> Drop for RcuCell<T> {
+    fn drop(&mut self) {
+        drop(unsafe { Arc::from_raw(self.ptr.load().cast::<T>()) });
+    }
+}
+
+impl<T> RcuCell<T> {
+    /// Create a new cell publishing `value`
+    pub fn new(value: Arc<T>) -> Self {
+        Self {
+            ptr: SubatomicPtr::new(Arc::into_raw(value).cast::<()>()),
+            readers: AtomicUsize::new(0),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a snapshot of the currently published value.
+    pub fn load(&self) -> Arc<T> {
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.ptr.load().cast::<T>();
+        let snapshot = unsafe {
+            Arc::increment_strong_count(ptr);
+            Arc::from_raw(ptr)
+        };
+        self.readers.fetch_sub(1, Ordering::SeqCst);
+        snapshot
+    }
+
+    /// Publishes `value`, releasing the previously published value once no reader is cloning it.
+    pub fn store(&self, value: Arc<T>) {
+        drop(self.swap(value));
+    }
+
+    /// Publishes `value`, returning the previously published value once no reader is cloning it.
+    pub fn swap(&self, value: Arc<T>) -> Arc<T> {
+        let previous = self.ptr.swap(Arc::into_raw(value).cast::<()>());
+        while self.readers.load(Ordering::SeqCst) != 0 {
+            spin_loop();
+        }
+        unsafe { Arc::from_raw(previous.cast::<T>()) }
+    }
+}
@@ -0,0 +1,120 @@
+use core::{cell::UnsafeCell, fmt, mem::MaybeUninit, sync::atomic::Ordering};
+
+use crate::{
+    atomic::{fence, spin_loop},
+    ordering::SeqCst,
+    Subatomic64,
+};
+
+/// A sequence lock, giving lock-free reads of a Copy value too large for a single atomic.
+/// A `Subatomic64<u64, SeqCst>` version counter is odd while a write is in progress; readers retry until they observe the same even version before and after copying the value.
+/// Writers exclude each other by moving the version from even to odd.
+///
+/// Readers copy the value while a writer may be modifying it, using volatile reads and discarding any copy made during a write.
+/// The copy is kept as a `MaybeUninit<T>` until the version check confirms it, so a torn T is never materialized.
+pub struct SeqLock<T: Copy> {
+    version: Subatomic64<u64, SeqCst>,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: Copy + Send> Sync for SeqLock<T> {}
+
+impl<T: Copy + Default> Default for SeqLock<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for SeqLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Debug>::fmt(&self.read(), f)
+    }
+}
+
+/// Ends a write on drop, so a panicking writer does not leave the lock held.
+struct WriteGuard<'a> {
+    version: &'a Subatomic64<u64, SeqCst>,
+    started: u64,
+}
+
+impl Drop for WriteGuard<'_> {
+    fn drop(&mut self) {
+        self.version.store_with(self.started + 2, Ordering::Release);
+    }
+}
+
+impl<T: Copy> SeqLock<T> {
+    /// Create a new sequence lock
+    pub fn new(value: T) -> Self {
+        Self {
+            version: Subatomic64::with_ordering(0, SeqCst),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Reads a consistent copy of the value, retrying while a write is in progress or if one happened during the read.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn read(&self) -> T {
+        loop {
+            let before = self.version.load_with(Ordering::Acquire);
+            if before % 2 == 1 {
+                spin_loop();
+                continue;
+            }
+            let value = unsafe { self.value.get().cast::<MaybeUninit<T>>().read_volatile() };
+            fence(Ordering::Acquire);
+            if self.version.load_with(Ordering::Relaxed) == before {
+                return unsafe { value.assume_init() };
+            }
+        }
+    }
+
+    /// Modifies the value with `f`, waiting for any other writer to finish first.
+    /// Readers retry until the write completes, which also happens if `f` panics, in which case the value is left unchanged.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn write<F: FnOnce(&mut T)>(&self, f: F) {
+        let mut started = self.version.load_with(Ordering::Relaxed);
+        loop {
+            if started % 2 == 1 {
+                spin_loop();
+                started = self.version.load_with(Ordering::Relaxed);
+                continue;
+            }
+            match self.version.compare_exchange_with(
+                started,
+                started + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => started = actual,
+            }
+        }
+        let guard = WriteGuard {
+            version: &self.version,
+            started,
+        };
+        fence(Ordering::Release);
+
+        let mut value = unsafe { self.value.get().read_volatile() };
+        f(&mut value);
+        unsafe { self.value.get().write_volatile(value) };
+        drop(guard);
+    }
+
+    /// Replaces the value, waiting for any other writer to finish first.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store(&self, value: T) {
+        self.write(|current| *current = value);
+    }
+
+    /// Consumes the lock and returns the value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Returns a mutable reference to the value through exclusive access, without any synchronization.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
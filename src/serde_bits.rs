@@ -0,0 +1,135 @@
+//! Serializes an atomic wrapper as its raw backing integer instead of as T, for use with `#[serde(with = "subatomic::serde_bits")]`.
+//! This is useful when the bit pattern is the canonical on-disk format, e.g. when T's own `Serialize` is lossy or unstable.
+//!
+//! The backing integer is read in host byte order, so the serialized number for a `Pod` T such as `[u8; 8]` differs between
+//! little- and big-endian machines. The `le` submodule serializes the bytes of such a T as a little-endian integer instead,
+//! which reads back the same bytes on every architecture. Integer and float T hold a number rather than bytes,
+//! so both paths serialize their backing integer unchanged, which is the same number on every architecture.
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+
+    /// Types for which every bit pattern of their size is a valid value, so deserialized bits can be reinterpreted as them.
+    pub trait AnyBits: Copy + 'static {}
+
+    #[cfg(not(feature = "bytemuck"))]
+    impl<T: crate::Arithmetic + 'static> AnyBits for T {}
+    #[cfg(feature = "bytemuck")]
+    impl<T: bytemuck::Pod> AnyBits for T {}
+
+    /// Whether T is an integer or float, whose backing integer is its numeric value in every byte order,
+    /// rather than a byte layout such as `[u8; N]` whose backing integer depends on the host byte order.
+    pub(crate) fn is_number<T: 'static>() -> bool {
+        use core::any::TypeId;
+
+        let id = TypeId::of::<T>();
+        [
+            TypeId::of::<u8>(),
+            TypeId::of::<u16>(),
+            TypeId::of::<u32>(),
+            TypeId::of::<u64>(),
+            TypeId::of::<u128>(),
+            TypeId::of::<usize>(),
+            TypeId::of::<i8>(),
+            TypeId::of::<i16>(),
+            TypeId::of::<i32>(),
+            TypeId::of::<i64>(),
+            TypeId::of::<i128>(),
+            TypeId::of::<isize>(),
+            TypeId::of::<f32>(),
+            TypeId::of::<f64>(),
+        ]
+        .contains(&id)
+    }
+}
+
+/// Atomic wrappers that can be converted to and from their raw backing integer.
+/// Deserialized bits are reinterpreted as T without a check, so this is only implemented for wrappers over integer T,
+/// or over any `Pod` T with the `bytemuck` feature, for which every bit pattern is valid.
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SerdeBits: sealed::Sealed + Sized {
+    /// The backing integer.
+    type Bits: Serialize + DeserializeOwned;
+
+    /// Loads the raw backing integer.
+    fn to_bits(&self) -> Self::Bits;
+
+    /// Creates a wrapper from the raw backing integer.
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// Loads the bytes of the value as a little-endian integer, or the backing integer unchanged if T is an integer or float.
+    fn to_le_bits(&self) -> Self::Bits;
+
+    /// Creates a wrapper from bytes given as a little-endian integer, or from the backing integer if T is an integer or float.
+    fn from_le_bits(bits: Self::Bits) -> Self;
+}
+
+/// Serializes the raw backing integer of `atomic`.
+pub fn serialize<A: SerdeBits, S: Serializer>(
+    atomic: &A,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    atomic.to_bits().serialize(serializer)
+}
+
+/// Deserializes a raw backing integer into a new wrapper.
+pub fn deserialize<'de, A: SerdeBits, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<A, D::Error> {
+    A::Bits::deserialize(deserializer).map(A::from_bits)
+}
+
+/// Serializes the bytes of the value as a little-endian integer, for use with `#[serde(with = "subatomic::serde_bits::le")]`.
+/// Unlike the host-endian functions of the parent module, the same bytes are read back on every architecture.
+/// An integer or float T is serialized as its value, e.g. `1u64` as `1`, which is likewise the same on every architecture.
+pub mod le {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::SerdeBits;
+
+    /// Serializes the bytes of `atomic` as a little-endian integer.
+    pub fn serialize<A: SerdeBits, S: Serializer>(
+        atomic: &A,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        atomic.to_le_bits().serialize(serializer)
+    }
+
+    /// Deserializes a little-endian integer into a new wrapper holding those bytes.
+    pub fn deserialize<'de, A: SerdeBits, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<A, D::Error> {
+        A::Bits::deserialize(deserializer).map(A::from_le_bits)
+    }
+}
+
+#[cfg(all(test, not(feature = "loom"), feature = "u64"))]
+mod tests {
+    use serde_json::json;
+
+    use crate::Subatomic64;
+
+    /// The expected values are fixed, so these tests check the same serialized form on little- and big-endian hosts.
+    #[test]
+    fn le_serializes_integers_as_their_value() {
+        let value = Subatomic64::new(0x0102_0304_0506_0708u64);
+        let serialized = super::le::serialize(&value, serde_json::value::Serializer).unwrap();
+        assert_eq!(serialized, json!(0x0102_0304_0506_0708u64));
+        let value: Subatomic64<u64> =
+            super::le::deserialize(json!(0x0102_0304_0506_0708u64)).unwrap();
+        assert_eq!(value.load(), 0x0102_0304_0506_0708);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn le_serializes_bytes_as_little_endian() {
+        let value = Subatomic64::new([1u8, 2, 3, 4, 5, 6, 7, 8]);
+        let serialized = super::le::serialize(&value, serde_json::value::Serializer).unwrap();
+        assert_eq!(serialized, json!(0x0807_0605_0403_0201u64));
+        let value: Subatomic64<[u8; 8]> =
+            super::le::deserialize(json!(0x0807_0605_0403_0201u64)).unwrap();
+        assert_eq!(value.load(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+}
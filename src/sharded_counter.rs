@@ -0,0 +1,60 @@
+use core::fmt;
+
+use crate::{ordering::Relaxed, CachePadded, Subatomic64};
+
+/// A `u64` counter split over N cache-padded shards, so that concurrent writers to different shards never contend on a cache line.
+/// Writers pick a shard by index, e.g. a per-thread index, and `get` sums all shards.
+/// All operations are Ordering::Relaxed, and `get` is approximate while writers are active, since each shard is read separately.
+pub struct ShardedCounter<const N: usize> {
+    shards: [CachePadded<Subatomic64<u64, Relaxed>>; N],
+}
+
+impl<const N: usize> Default for ShardedCounter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for ShardedCounter<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <u64 as fmt::Debug>::fmt(&self.get(), f)
+    }
+}
+
+impl<const N: usize> ShardedCounter<N> {
+    /// Evaluated per monomorphization by the constructor, so a counter without shards fails to compile.
+    const SHARD_CHECK: () = assert!(N > 0, "ShardedCounter requires at least one shard");
+
+    /// Create a new counter with every shard at zero
+    pub fn new() -> Self {
+        let () = Self::SHARD_CHECK;
+        Self {
+            shards: core::array::from_fn(|_| {
+                CachePadded::new(Subatomic64::with_ordering(0, Relaxed))
+            }),
+        }
+    }
+
+    /// Adds `val` to the shard selected by `shard`, which is taken modulo N.
+    /// This operation wraps around on overflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn add(&self, shard: usize, val: u64) {
+        self.shards[shard % N].fetch_add(val);
+    }
+
+    /// Adds one to the shard selected by `shard`, which is taken modulo N.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn increment(&self, shard: usize) {
+        self.add(shard, 1);
+    }
+
+    /// Returns the sum of all shards, wrapping around on overflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn get(&self) -> u64 {
+        let mut sum = 0u64;
+        for shard in &self.shards {
+            sum = sum.wrapping_add(shard.load());
+        }
+        sum
+    }
+}
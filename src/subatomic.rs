@@ -0,0 +1,177 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::{size_of, transmute_copy, ManuallyDrop, MaybeUninit},
+    sync::atomic::Ordering,
+};
+
+#[cfg(subatomic_has_64)]
+use crate::atomic::AtomicU64;
+use crate::{
+    atomic::{AtomicU16, AtomicU32, AtomicU8},
+    AtomicBacking,
+};
+
+/// Storage for every supported backing atomic, of which only the one matching the size of T is ever initialized.
+#[repr(C, align(8))]
+union Storage {
+    b8: ManuallyDrop<AtomicU8>,
+    b16: ManuallyDrop<AtomicU16>,
+    b32: ManuallyDrop<AtomicU32>,
+    #[cfg(subatomic_has_64)]
+    b64: ManuallyDrop<AtomicU64>,
+}
+
+/// Runs `$body` with `$atomic` bound to the backing atomic for the size of T.
+/// The size of T is a constant, so only one arm survives monomorphization.
+macro_rules! dispatch {
+    ($storage:expr, |$atomic:ident| $body:expr) => {
+        match size_of::<T>() {
+            1 => {
+                let $atomic: &AtomicU8 = unsafe { &$storage.b8 };
+                $body
+            }
+            2 => {
+                let $atomic: &AtomicU16 = unsafe { &$storage.b16 };
+                $body
+            }
+            4 => {
+                let $atomic: &AtomicU32 = unsafe { &$storage.b32 };
+                $body
+            }
+            #[cfg(subatomic_has_64)]
+            _ => {
+                let $atomic: &AtomicU64 = unsafe { &$storage.b64 };
+                $body
+            }
+            #[cfg(not(subatomic_has_64))]
+            _ => unreachable!(),
+        }
+    };
+}
+
+/// Wraps a 1, 2, 4 or 8-byte length T in an atomic, picking the backing atomic from the size of T at compile time.
+/// All operations are Ordering::SeqCst.
+///
+/// The wrapper always occupies 8 bytes, as Rust cannot size a type after the size of its parameter.
+/// Like the fixed-size wrappers, T must not contain padding or other uninitialized bytes.
+pub struct Subatomic<T: Copy + 'static> {
+    storage: Storage,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Copy + Default + 'static> Default for Subatomic<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Copy + fmt::Debug + 'static> fmt::Debug for Subatomic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Debug>::fmt(&self.load(), f)
+    }
+}
+
+impl<T: Copy + fmt::Display + 'static> fmt::Display for Subatomic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Display>::fmt(&self.load(), f)
+    }
+}
+
+impl<T: Copy + 'static> Subatomic<T> {
+    /// Evaluated per monomorphization by the constructor, so a T of an unsupported size fails to compile.
+    #[cfg(subatomic_has_64)]
+    const SIZE_CHECK: () = assert!(
+        matches!(size_of::<T>(), 1 | 2 | 4 | 8),
+        "Subatomic requires a 1, 2, 4 or 8-byte T"
+    );
+
+    /// Evaluated per monomorphization by the constructor, so a T of an unsupported size fails to compile.
+    /// 8-byte T need 64-bit atomics, which this target lacks without the `portable-atomic` feature.
+    #[cfg(not(subatomic_has_64))]
+    const SIZE_CHECK: () = assert!(
+        matches!(size_of::<T>(), 1 | 2 | 4),
+        "Subatomic requires a 1, 2 or 4-byte T on targets without 64-bit atomics"
+    );
+
+    /// Converts `item` to the backing integer, zero-filling any bytes not written by the copy of `item`.
+    fn encode<B: Copy>(item: T) -> B {
+        let mut bits = MaybeUninit::<B>::zeroed();
+        unsafe {
+            bits.as_mut_ptr().cast::<T>().write(item);
+            bits.assume_init()
+        }
+    }
+
+    /// Reinterprets the backing integer as `T`.
+    fn decode<B: Copy>(bits: B) -> T {
+        unsafe { transmute_copy(&bits) }
+    }
+
+    /// Create a new atomic wrapper
+    pub fn new(item: T) -> Self {
+        let () = Self::SIZE_CHECK;
+        let storage = match size_of::<T>() {
+            1 => Storage {
+                b8: ManuallyDrop::new(AtomicBacking::new(Self::encode(item))),
+            },
+            2 => Storage {
+                b16: ManuallyDrop::new(AtomicBacking::new(Self::encode(item))),
+            },
+            4 => Storage {
+                b32: ManuallyDrop::new(AtomicBacking::new(Self::encode(item))),
+            },
+            #[cfg(subatomic_has_64)]
+            _ => Storage {
+                b64: ManuallyDrop::new(AtomicBacking::new(Self::encode(item))),
+            },
+            #[cfg(not(subatomic_has_64))]
+            _ => unreachable!(),
+        };
+        Self {
+            storage,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Update the interior value of the atomic wrapper
+    pub fn store(&self, item: T) {
+        dispatch!(self.storage, |atomic| AtomicBacking::store(
+            atomic,
+            Self::encode(item),
+            Ordering::SeqCst
+        ))
+    }
+
+    /// Swap the interior value of the atomic wrapper, returning the previous value
+    pub fn swap(&self, item: T) -> T {
+        dispatch!(self.storage, |atomic| Self::decode(AtomicBacking::swap(
+            atomic,
+            Self::encode(item),
+            Ordering::SeqCst
+        )))
+    }
+
+    /// Loads the interior value of the atomic wrapper.
+    pub fn load(&self) -> T {
+        dispatch!(self.storage, |atomic| Self::decode(AtomicBacking::load(
+            atomic,
+            Ordering::SeqCst
+        )))
+    }
+
+    /// Stores a value into the atomic integer if the current value is the same as the current value.
+    /// The return value is a result indicating whether the new value was written and containing the previous value.
+    /// On success this value is guaranteed to be equal to current.
+    pub fn compare_exchange(&self, current: T, new: T) -> Result<T, T> {
+        dispatch!(self.storage, |atomic| AtomicBacking::compare_exchange(
+            atomic,
+            Self::encode(current),
+            Self::encode(new),
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        )
+        .map(Self::decode)
+        .map_err(Self::decode))
+    }
+}
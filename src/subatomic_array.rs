@@ -0,0 +1,110 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::{transmute_copy, MaybeUninit},
+    sync::atomic::Ordering,
+};
+
+use crate::atomic::AtomicU64;
+
+/// A fixed-size array of independently atomic 8-byte length T cells, all operations are Ordering::SeqCst unless documented otherwise.
+/// Stores the cells as a plain `[AtomicU64; N]`, a more compact layout than an array of `Subatomic64`.
+/// Indexing panics when out of bounds, like slice indexing.
+pub struct SubatomicArray<T: Copy + 'static, const N: usize> {
+    inner: [AtomicU64; N],
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Copy + Default + 'static, const N: usize> Default for SubatomicArray<T, N> {
+    fn default() -> Self {
+        Self::new([T::default(); N])
+    }
+}
+
+impl<T: Copy + fmt::Debug + 'static, const N: usize> fmt::Debug for SubatomicArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..N).map(|index| self.load(index)))
+            .finish()
+    }
+}
+
+impl<T: Copy + 'static, const N: usize> SubatomicArray<T, N> {
+    /// Evaluated per monomorphization by the constructor, so a T that is not exactly 8 bytes fails to compile.
+    const SIZE_CHECK: () = assert!(
+        core::mem::size_of::<T>() == core::mem::size_of::<u64>(),
+        "SubatomicArray requires an 8-byte T"
+    );
+
+    /// Converts `item` to the backing integer, zero-filling any bytes not written by the copy of `item`.
+    fn encode(item: T) -> u64 {
+        let mut bits = MaybeUninit::new(0u64);
+        unsafe {
+            bits.as_mut_ptr().cast::<T>().write(item);
+            bits.assume_init()
+        }
+    }
+
+    /// Reinterprets the backing integer as `T`.
+    fn decode(bits: u64) -> T {
+        unsafe { transmute_copy(&bits) }
+    }
+
+    /// Create a new atomic array from its initial values
+    pub fn new(items: [T; N]) -> Self {
+        let () = Self::SIZE_CHECK;
+        Self {
+            inner: items.map(|item| AtomicU64::new(Self::encode(item))),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of cells in the array.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the array has no cells.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Update the value of the cell at `index`
+    pub fn store(&self, index: usize, item: T) {
+        self.inner[index].store(Self::encode(item), Ordering::SeqCst);
+    }
+
+    /// Swap the value of the cell at `index`, returning the previous value
+    pub fn swap(&self, index: usize, item: T) -> T {
+        Self::decode(self.inner[index].swap(Self::encode(item), Ordering::SeqCst))
+    }
+
+    /// Loads the value of the cell at `index`.
+    pub fn load(&self, index: usize) -> T {
+        Self::decode(self.inner[index].load(Ordering::SeqCst))
+    }
+
+    /// Stores a value into the cell at `index` if its value is the same as the current value.
+    /// The return value is a result indicating whether the new value was written and containing the previous value.
+    /// On success this value is guaranteed to be equal to current.
+    pub fn compare_exchange(&self, index: usize, current: T, new: T) -> Result<T, T> {
+        self.inner[index]
+            .compare_exchange(
+                Self::encode(current),
+                Self::encode(new),
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .map(Self::decode)
+            .map_err(Self::decode)
+    }
+
+    /// Folds over the values of every cell in index order, e.g. to sum the shards of a sharded counter.
+    /// Each cell is loaded separately with Ordering::Relaxed, so this is not a consistent snapshot of the array:
+    /// concurrent updates may be reflected for some cells and not others.
+    pub fn snapshot_fold<B, F: FnMut(B, T) -> B>(&self, init: B, mut f: F) -> B {
+        self.inner.iter().fold(init, |acc, cell| {
+            f(acc, Self::decode(cell.load(Ordering::Relaxed)))
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use core::{fmt, marker::PhantomData, sync::atomic::Ordering};
+
+use crate::{
+    atomic::AtomicBool,
+    ordering::{load_ordering, store_ordering, DefaultOrdering, SeqCst},
+};
+
+/// An atomic boolean flag.
+/// Operations without an explicit ordering use the default ordering O, one of the markers in the `ordering` module.
+/// It is `ordering::SeqCst` unless another default was chosen with `with_ordering` or spelled out in the type,
+/// e.g. `SubatomicBool<ordering::Relaxed>`, and unlike the width wrappers it is not changed by the `relaxed-default` feature.
+pub struct SubatomicBool<O: DefaultOrdering = SeqCst> {
+    inner: AtomicBool,
+    _phantom: PhantomData<O>,
+}
+
+impl<O: DefaultOrdering> Default for SubatomicBool<O> {
+    fn default() -> Self {
+        Self::from_value(false)
+    }
+}
+
+impl<O: DefaultOrdering> fmt::Debug for SubatomicBool<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <bool as fmt::Debug>::fmt(&self.load(), f)
+    }
+}
+
+impl<O: DefaultOrdering> fmt::Display for SubatomicBool<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <bool as fmt::Display>::fmt(&self.load(), f)
+    }
+}
+
+impl<O: DefaultOrdering> From<bool> for SubatomicBool<O> {
+    fn from(item: bool) -> Self {
+        Self::from_value(item)
+    }
+}
+
+impl SubatomicBool {
+    /// Create a new atomic flag
+    pub fn new(item: bool) -> Self {
+        Self::from_value(item)
+    }
+}
+
+impl<O: DefaultOrdering> SubatomicBool<O> {
+    /// Create a new atomic flag whose default ordering is the marker `ordering`, e.g. `SubatomicBool::with_ordering(false, ordering::Relaxed)`.
+    /// Loads and stores clamp it to the closest valid ordering, as for `Subatomic64::with_ordering`.
+    pub fn with_ordering(item: bool, _ordering: O) -> Self {
+        Self::from_value(item)
+    }
+
+    /// Wraps `item` with the default ordering of the type.
+    fn from_value(item: bool) -> Self {
+        Self {
+            inner: AtomicBool::new(item),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Update the flag
+    pub fn store(&self, item: bool) {
+        self.store_with(item, store_ordering(O::ORDERING));
+    }
+
+    /// Update the flag with the given memory ordering.
+    /// Panics if `ordering` is `Acquire` or `AcqRel`.
+    pub fn store_with(&self, item: bool, ordering: Ordering) {
+        debug_assert!(
+            ordering != Ordering::Acquire,
+            "there is no such thing as an acquire store"
+        );
+        debug_assert!(
+            ordering != Ordering::AcqRel,
+            "there is no such thing as an acquire-release store"
+        );
+        self.inner.store(item, ordering);
+    }
+
+    /// Swap the flag, returning the previous value
+    pub fn swap(&self, item: bool) -> bool {
+        self.inner.swap(item, O::ORDERING)
+    }
+
+    /// Loads the flag.
+    pub fn load(&self) -> bool {
+        self.load_with(load_ordering(O::ORDERING))
+    }
+
+    /// Loads the flag with the given memory ordering.
+    /// Panics if `ordering` is `Release` or `AcqRel`.
+    pub fn load_with(&self, ordering: Ordering) -> bool {
+        debug_assert!(
+            ordering != Ordering::Release,
+            "there is no such thing as a release load"
+        );
+        debug_assert!(
+            ordering != Ordering::AcqRel,
+            "there is no such thing as an acquire-release load"
+        );
+        self.inner.load(ordering)
+    }
+
+    /// Sets the flag, returning whether it was already set.
+    pub fn test_and_set(&self) -> bool {
+        self.swap(true)
+    }
+
+    /// Clears the flag.
+    pub fn clear(&self) {
+        self.store(false);
+    }
+}
@@ -0,0 +1,64 @@
+use core::{fmt, marker::PhantomData};
+
+use crate::{
+    atomic::AtomicU32,
+    ordering::{load_ordering, DefaultOrdering, SeqCst},
+};
+
+/// A one-shot latch, for "first thread does setup" patterns: exactly one call to `try_latch` wins.
+/// Once latched it stays latched. Note that `is_latched` only reports that some thread won, not that its setup has finished.
+/// Operations use the ordering named by the marker O, `ordering::SeqCst` unless another one was chosen with `with_ordering`
+/// or spelled out in the type, e.g. `SubatomicLatch<ordering::AcqRel>`.
+pub struct SubatomicLatch<O: DefaultOrdering = SeqCst> {
+    inner: AtomicU32,
+    _phantom: PhantomData<O>,
+}
+
+impl<O: DefaultOrdering> Default for SubatomicLatch<O> {
+    fn default() -> Self {
+        Self::from_value()
+    }
+}
+
+impl<O: DefaultOrdering> fmt::Debug for SubatomicLatch<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubatomicLatch")
+            .field("latched", &self.is_latched())
+            .finish()
+    }
+}
+
+impl SubatomicLatch {
+    /// Create a new, unlatched latch
+    pub fn new() -> Self {
+        Self::from_value()
+    }
+}
+
+impl<O: DefaultOrdering> SubatomicLatch<O> {
+    /// Create a new, unlatched latch whose operations use the marker `ordering`, e.g. `SubatomicLatch::with_ordering(ordering::AcqRel)`.
+    /// Loads clamp it to the closest valid ordering, as for `Subatomic64::with_ordering`.
+    pub fn with_ordering(_ordering: O) -> Self {
+        Self::from_value()
+    }
+
+    /// Creates an unlatched latch with the ordering of the type.
+    fn from_value() -> Self {
+        Self {
+            inner: AtomicU32::new(0),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Latches, returning true if this call was the one to latch it and false if it was already latched.
+    pub fn try_latch(&self) -> bool {
+        self.inner
+            .compare_exchange(0, 1, O::ORDERING, load_ordering(O::ORDERING))
+            .is_ok()
+    }
+
+    /// Returns true if the latch has been latched.
+    pub fn is_latched(&self) -> bool {
+        self.inner.load(load_ordering(O::ORDERING)) != 0
+    }
+}
@@ -0,0 +1,93 @@
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+/// Exclusive access to the value of an atomic wrapper, returned by `get_mut` on the `SubatomicN` types.
+/// It derefs to `&mut T`, so setup code holding `&mut` access can mutate the value like a plain field.
+///
+/// The guard borrows the wrapper mutably, so no other thread can access the wrapper while it is alive,
+/// and no atomic operations are needed. Writes go straight to the backing integer, except with loom atomics,
+/// which cannot be borrowed mutably, where the guard works on a copy and writes it back on drop.
+pub struct SubatomicMut<'a, T: Copy + 'static> {
+    slot: Slot<'a, T>,
+}
+
+enum Slot<'a, T> {
+    Direct(&'a mut T),
+    #[cfg(feature = "loom")]
+    Copied {
+        value: T,
+        target: &'a mut dyn WriteBack<T>,
+    },
+}
+
+/// Stores a value into a wrapper through exclusive access, for the write-back of `SubatomicMut` over loom atomics.
+#[cfg(feature = "loom")]
+pub(crate) trait WriteBack<T> {
+    fn write_back(&mut self, item: T);
+}
+
+impl<'a, T: Copy + 'static> SubatomicMut<'a, T> {
+    #[allow(dead_code)]
+    pub(crate) fn new(value: &'a mut T) -> Self {
+        Self {
+            slot: Slot::Direct(value),
+        }
+    }
+
+    #[cfg(feature = "loom")]
+    pub(crate) fn with_write_back(value: T, target: &'a mut dyn WriteBack<T>) -> Self {
+        Self {
+            slot: Slot::Copied { value, target },
+        }
+    }
+}
+
+#[cfg(feature = "loom")]
+impl<T: Copy + 'static> Drop for SubatomicMut<'_, T> {
+    fn drop(&mut self) {
+        if let Slot::Copied { value, target } = &mut self.slot {
+            target.write_back(*value);
+        }
+    }
+}
+
+impl<T: Copy + 'static> Deref for SubatomicMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.slot {
+            Slot::Direct(value) => value,
+            #[cfg(feature = "loom")]
+            Slot::Copied { value, .. } => value,
+        }
+    }
+}
+
+impl<T: Copy + 'static> DerefMut for SubatomicMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match &mut self.slot {
+            Slot::Direct(value) => value,
+            #[cfg(feature = "loom")]
+            Slot::Copied { value, .. } => value,
+        }
+    }
+}
+
+impl<T: Copy + fmt::Debug + 'static> fmt::Debug for SubatomicMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "loom")]
+impl<T: Copy + 'static, const BYTES: usize, O: crate::ordering::DefaultOrdering> WriteBack<T>
+    for crate::SubatomicN<T, BYTES, O>
+where
+    crate::Bytes<BYTES>: crate::SupportedWidth,
+{
+    fn write_back(&mut self, item: T) {
+        self.store_mut(item);
+    }
+}
@@ -0,0 +1,1686 @@
+use core::{
+    cmp, fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::{size_of, transmute_copy, MaybeUninit},
+    sync::atomic::Ordering,
+};
+
+#[cfg(all(feature = "u128", subatomic_has_128))]
+use crate::atomic::AtomicU128;
+#[cfg(subatomic_has_16)]
+use crate::atomic::AtomicU16;
+#[cfg(subatomic_has_32)]
+use crate::atomic::AtomicU32;
+#[cfg(subatomic_has_64)]
+use crate::atomic::AtomicU64;
+#[cfg(subatomic_has_8)]
+use crate::atomic::AtomicU8;
+use crate::{
+    atomic::spin_loop,
+    backoff::Backoff,
+    marker::sealed::Sealed as _,
+    ordering::{load_ordering, store_ordering, CrateDefault, DefaultOrdering},
+    Arithmetic, AtomicBacking, BitOps, CasOutcome, FetchUpdateError, InvalidBits, RawBits,
+    SizeMismatch, SubatomicMut,
+};
+#[cfg(feature = "quickcheck")]
+use alloc::boxed::Box;
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A size in bytes, used to select the backing atomic of a `SubatomicN`.
+pub struct Bytes<const N: usize>;
+
+/// Sizes in bytes that have a backing atomic, implemented for `Bytes<1>`, `Bytes<2>`, `Bytes<4>`, `Bytes<8>` and, with the `u128` feature, `Bytes<16>`.
+/// Sizes whose atomics lack compare-exchange on the target are left out unless the `portable-atomic` feature is enabled.
+pub trait SupportedWidth {
+    /// The atomic integer of this size.
+    type Atomic: AtomicBacking;
+}
+
+#[cfg(subatomic_has_8)]
+impl SupportedWidth for Bytes<1> {
+    type Atomic = AtomicU8;
+}
+
+#[cfg(subatomic_has_16)]
+impl SupportedWidth for Bytes<2> {
+    type Atomic = AtomicU16;
+}
+
+#[cfg(subatomic_has_32)]
+impl SupportedWidth for Bytes<4> {
+    type Atomic = AtomicU32;
+}
+
+#[cfg(subatomic_has_64)]
+impl SupportedWidth for Bytes<8> {
+    type Atomic = AtomicU64;
+}
+
+#[cfg(all(feature = "u128", subatomic_has_128))]
+impl SupportedWidth for Bytes<16> {
+    type Atomic = AtomicU128;
+}
+
+/// The backing atomic for BYTES.
+type AtomicOf<const BYTES: usize> = <Bytes<BYTES> as SupportedWidth>::Atomic;
+
+/// The integer stored by the backing atomic for BYTES.
+type BitsOf<const BYTES: usize> = <AtomicOf<BYTES> as AtomicBacking>::Bits;
+
+/// Wraps a BYTES-byte length T in the atomic of that size, e.g. `SubatomicN<MyType, 8>`.
+/// `Subatomic8`, `Subatomic16`, `Subatomic32`, `Subatomic64` and `Subatomic128` are aliases for the supported sizes.
+/// A BYTES without a backing atomic is rejected by the `SupportedWidth` bound, and a T of a different size fails to build.
+/// The size check is an associated const evaluated after monomorphization, so `cargo check` only reports it in const contexts
+/// such as a `static` initialized with `from_bits`, and otherwise it is reported by `cargo build`.
+/// Operations without an explicit ordering use the default ordering O, one of the markers in the `ordering` module.
+/// It is `ordering::SeqCst` unless another default was chosen with `with_ordering` or spelled out in the type,
+/// e.g. `Subatomic64<u64, ordering::Relaxed>`. With the `relaxed-default` feature, it is `ordering::Relaxed` instead.
+///
+/// T is reinterpreted as the backing integer, so it must not contain padding or other uninitialized bytes:
+/// reading those as part of the integer is undefined behavior. T is written over a zeroed integer,
+/// but Rust does not guarantee that padding bytes are preserved by a copy.
+/// With the `bytemuck` feature, `new_no_uninit` checks this requirement at compile time.
+///
+/// Values are copied in and out with `transmute_copy`, which never runs destructors. This is sound because T: Copy,
+/// and a Copy type can neither implement Drop nor contain a field that does, so it has no drop glue to skip.
+/// The value constructors also check `needs_drop::<T>()` in debug builds.
+///
+/// Niche types such as `NonZeroU64` or `NonNull<T>` need no runtime check that the backing integer holds a valid T:
+/// the value operations only ever store a T, the raw-bit entry points `from_bits`, `store_bits` and `inner` only exist for integer T,
+/// and `clear_bits` requires a `Zeroable` T, so a load cannot produce an invalid value such as a zero `NonZeroU64`.
+/// Only the unsafe `from_atomic` takes arbitrary bits, and its safety contract requires them to be a valid T.
+///
+/// The struct is `#[repr(transparent)]` over the backing atomic, as the default ordering is part of the type rather than stored,
+/// so it has exactly the size, alignment and ABI of the backing atomic, e.g. `AtomicU64` for `Subatomic64`.
+/// It can be placed in `#[repr(C)]` structs shared across an FFI boundary, and a pointer to it is a valid pointer to the backing atomic.
+#[repr(transparent)]
+pub struct SubatomicN<T: Copy + 'static, const BYTES: usize, O: DefaultOrdering = CrateDefault>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    inner: AtomicOf<BYTES>,
+    _phantom: PhantomData<(T, O)>,
+}
+
+/// Values only ever leave the wrapper as copies, so sharing it between threads amounts to sending T, as with `Mutex<T>`.
+/// Raw pointers are not Send, so a pointer T needs a newtype that implements Send for the wrapper to be Send and Sync.
+unsafe impl<T: Copy + Send + 'static, const BYTES: usize, O: DefaultOrdering> Send
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+}
+unsafe impl<T: Copy + Send + 'static, const BYTES: usize, O: DefaultOrdering> Sync
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+}
+
+impl<T: Copy + Default + 'static, const BYTES: usize, O: DefaultOrdering> Default
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn default() -> Self {
+        Self::from_value(T::default())
+    }
+}
+
+#[cfg(not(feature = "raw-debug"))]
+impl<T: Copy + fmt::Debug + 'static, const BYTES: usize, O: DefaultOrdering> fmt::Debug
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Debug>::fmt(&self.load(), f)
+    }
+}
+
+/// With the `raw-debug` feature, Debug is implemented for any T and prints the backing integer in hex, e.g. `Subatomic64(0x2a)` for an 8-byte T,
+/// so types holding a wrapper around a non-Debug T can still derive Debug.
+#[cfg(feature = "raw-debug")]
+impl<T: Copy + 'static, const BYTES: usize, O: DefaultOrdering> fmt::Debug
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Subatomic{}({:?})", BYTES * 8, self.debug_bits())
+    }
+}
+
+impl<T: Copy + fmt::Display + 'static, const BYTES: usize, O: DefaultOrdering> fmt::Display
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Display>::fmt(&self.load(), f)
+    }
+}
+
+/// Cloning takes a snapshot of the current value into a new, independent atomic with the same default ordering.
+/// The clone does not stay in sync with the original.
+impl<T: Copy + 'static, const BYTES: usize, O: DefaultOrdering> Clone for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: AtomicBacking::new(self.to_bits()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Equality is evaluated with a separate load of each side, so it is only meaningful under external synchronization.
+impl<T: Copy + PartialEq + 'static, const BYTES: usize, O: DefaultOrdering> PartialEq
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.load() == other.load()
+    }
+}
+
+/// Equality is evaluated with a load of the current value, so it is only meaningful under external synchronization.
+impl<T: Copy + PartialEq + 'static, const BYTES: usize, O: DefaultOrdering> PartialEq<T>
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.load() == *other
+    }
+}
+
+impl<T: Copy + Eq + 'static, const BYTES: usize, O: DefaultOrdering> Eq for SubatomicN<T, BYTES, O> where
+    Bytes<BYTES>: SupportedWidth
+{
+}
+
+/// Hashes a load of the current value, so it is only meaningful under external synchronization.
+impl<T: Copy + Hash + 'static, const BYTES: usize, O: DefaultOrdering> Hash
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.load().hash(state);
+    }
+}
+
+impl<T: Copy + 'static, const BYTES: usize, O: DefaultOrdering> From<T> for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn from(item: T) -> Self {
+        Self::from_value(item)
+    }
+}
+
+/// Compares a separate load of each side, so it is only meaningful under external synchronization.
+impl<T: Copy + PartialOrd + 'static, const BYTES: usize, O: DefaultOrdering> PartialOrd
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.load().partial_cmp(&other.load())
+    }
+}
+
+/// Compares a separate load of each side, so it is only meaningful under external synchronization.
+impl<T: Copy + Ord + 'static, const BYTES: usize, O: DefaultOrdering> Ord
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.load().cmp(&other.load())
+    }
+}
+
+/// Same as `inner`, bypassing the conversion to and from T, and likewise only available for integer T.
+impl<T: Arithmetic + 'static, const BYTES: usize, O: DefaultOrdering> AsRef<AtomicOf<BYTES>>
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn as_ref(&self) -> &AtomicOf<BYTES> {
+        let () = Self::SIZE_CHECK;
+        &self.inner
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy + Serialize + 'static, const BYTES: usize, O: DefaultOrdering> Serialize
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.load().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Copy + Deserialize<'de> + 'static, const BYTES: usize, O: DefaultOrdering>
+    Deserialize<'de> for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from_value(T::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Copy + Arbitrary<'a> + 'static, const BYTES: usize, O: DefaultOrdering> Arbitrary<'a>
+    for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_value(T::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<
+        T: Copy + proptest::arbitrary::Arbitrary + 'static,
+        const BYTES: usize,
+        O: DefaultOrdering,
+    > proptest::arbitrary::Arbitrary for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    type Parameters = T::Parameters;
+    /// Shrinking is inherited from T's strategy through the map.
+    type Strategy = proptest::strategy::Map<T::Strategy, fn(T) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(
+            proptest::arbitrary::any_with::<T>(args),
+            Self::from_value,
+        )
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: Copy + quickcheck::Arbitrary + 'static, const BYTES: usize, O: DefaultOrdering>
+    quickcheck::Arbitrary for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::from_value(T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.load().shrink().map(Self::from_value))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: crate::serde_bits::sealed::AnyBits, const BYTES: usize, O: DefaultOrdering>
+    crate::serde_bits::sealed::Sealed for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+}
+
+#[cfg(feature = "serde")]
+impl<T: crate::serde_bits::sealed::AnyBits, const BYTES: usize, O: DefaultOrdering>
+    crate::serde_bits::SerdeBits for SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+    BitsOf<BYTES>: Serialize + serde::de::DeserializeOwned,
+{
+    type Bits = BitsOf<BYTES>;
+
+    fn to_bits(&self) -> BitsOf<BYTES> {
+        self.to_bits()
+    }
+
+    fn from_bits(bits: BitsOf<BYTES>) -> Self {
+        Self::from_raw(bits)
+    }
+
+    fn to_le_bits(&self) -> BitsOf<BYTES> {
+        if crate::serde_bits::sealed::is_number::<T>() {
+            self.to_bits()
+        } else {
+            self.to_bits().to_le()
+        }
+    }
+
+    fn from_le_bits(bits: BitsOf<BYTES>) -> Self {
+        if crate::serde_bits::sealed::is_number::<T>() {
+            Self::from_raw(bits)
+        } else {
+            Self::from_raw(BitsOf::<BYTES>::from_le(bits))
+        }
+    }
+}
+
+impl<T: Copy + 'static, const BYTES: usize, O: DefaultOrdering> SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Alignment of the wrapper in bytes, guaranteed at compile time to be at least the size of the backing integer, so the atomic is naturally aligned.
+    pub const ALIGN: usize = core::mem::align_of::<Self>();
+
+    /// Whether operations on the backing atomic are lock-free on every CPU this build can run on.
+    /// This is false only with the `portable-atomic` backend, when it may fall back to a global lock or to disabling interrupts.
+    /// Always true without it, as the native atomics only exist on targets supporting them without a lock.
+    pub const IS_ALWAYS_LOCK_FREE: bool = AtomicOf::<BYTES>::IS_ALWAYS_LOCK_FREE;
+
+    /// Returns whether operations on the backing atomic are lock-free on the running CPU.
+    /// With the `portable-atomic` backend this may detect CPU features at runtime, so it can be true when `IS_ALWAYS_LOCK_FREE` is not.
+    pub fn is_lock_free() -> bool {
+        AtomicOf::<BYTES>::is_lock_free()
+    }
+
+    /// Evaluated per monomorphization by the constructors, so a T that is not exactly BYTES bytes fails to build.
+    /// Being a post-monomorphization error, it is reported by `cargo build` but not by `cargo check`, except in const contexts.
+    const SIZE_CHECK: () = assert!(
+        size_of::<T>() == BYTES,
+        "the size of T must match the backing atomic"
+    );
+
+    /// Converts `item` to the backing integer, zero-filling any bytes not written by the copy of `item`.
+    fn encode(item: T) -> BitsOf<BYTES> {
+        let mut bits = MaybeUninit::new(BitsOf::<BYTES>::ZERO);
+        unsafe {
+            bits.as_mut_ptr().cast::<T>().write(item);
+            bits.assume_init()
+        }
+    }
+
+    /// Evaluated per monomorphization by `new_padded`, so a T larger than BYTES bytes fails to compile.
+    /// A 1-byte wrapper has no T smaller than its backing integer, so `new_padded` is rejected there too.
+    const PADDED_SIZE_CHECK: () = assert!(
+        size_of::<T>() <= BYTES && BYTES > 1,
+        "new_padded requires a T of at most BYTES bytes, on a wrapper of at least 2 bytes"
+    );
+
+    /// Reinterprets the backing integer as `T`.
+    fn decode(bits: BitsOf<BYTES>) -> T {
+        unsafe { transmute_copy(&bits) }
+    }
+
+    /// Create a new atomic wrapper whose default ordering is the marker `ordering`, e.g. `Subatomic64::with_ordering(0, ordering::Relaxed)`.
+    /// `load`, `store`, `swap` and the compare-exchange operations without an explicit ordering then use it.
+    /// Loads and stores clamp it to the closest valid ordering: `Release` and `AcqRel` loads become `Relaxed` and `Acquire`,
+    /// `Acquire` and `AcqRel` stores become `Relaxed` and `Release`.
+    /// Compare-exchange uses it on success and the clamped load ordering on failure, e.g. `AcqRel` maps to `(AcqRel, Acquire)`
+    /// and `Release` maps to `(Release, Relaxed)`.
+    pub fn with_ordering(item: T, _ordering: O) -> Self {
+        Self::from_value(item)
+    }
+
+    /// Wraps `item` with the default ordering of the type, for the generic trait impls and `with_ordering`.
+    fn from_value(item: T) -> Self {
+        let () = Self::SIZE_CHECK;
+        debug_assert!(
+            !core::mem::needs_drop::<T>(),
+            "SubatomicN requires a T without drop glue"
+        );
+        Self {
+            inner: AtomicBacking::new(Self::encode(item)),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Wraps the raw backing integer, for the generic callers of the per-width `from_bits`.
+    #[cfg(any(feature = "serde", feature = "loom"))]
+    fn from_raw(bits: BitsOf<BYTES>) -> Self {
+        let () = Self::SIZE_CHECK;
+        Self {
+            inner: AtomicBacking::new(bits),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Update the interior value of the atomic wrapper
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store(&self, item: T) {
+        self.store_with(item, store_ordering(O::ORDERING));
+    }
+
+    /// Update the interior value of the atomic wrapper with the given memory ordering.
+    /// Panics if `ordering` is `Acquire` or `AcqRel`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_with(&self, item: T, ordering: Ordering) {
+        debug_assert!(
+            ordering != Ordering::Acquire,
+            "there is no such thing as an acquire store"
+        );
+        debug_assert!(
+            ordering != Ordering::AcqRel,
+            "there is no such thing as an acquire-release store"
+        );
+        let bits = Self::encode(item);
+        trace_op!("store", bits = bits);
+        self.inner.store(bits, ordering);
+    }
+
+    /// Swap the interior value of the atomic wrapper, returning the previous value
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn swap(&self, item: T) -> T {
+        self.swap_with(item, O::ORDERING)
+    }
+
+    /// Swaps with the given memory ordering, shared by the swap methods.
+    #[cfg_attr(feature = "trace", track_caller)]
+    fn swap_with(&self, item: T, ordering: Ordering) -> T {
+        let bits = Self::encode(item);
+        let out = self.inner.swap(bits, ordering);
+        trace_op!("swap", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Replaces the interior value, returning the previous value.
+    /// An alias for `swap`, named after `Cell::replace`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn replace(&self, item: T) -> T {
+        self.swap(item)
+    }
+
+    /// Stores the current value of `other` into this wrapper.
+    /// This is a load of `other` followed by a separate store into `self`, not one atomic operation:
+    /// `other` may change in between, and the stored value may already be stale when this returns.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn copy_from(&self, other: &Self) {
+        self.store(other.load());
+    }
+
+    /// Exchanges the values of this wrapper and `other` in three separate steps: load `other`, swap that value into `self`,
+    /// and store the previous value of `self` into `other`.
+    /// The exchange is not atomic as a whole. Another thread can observe the same value in both wrappers between the steps,
+    /// and concurrent writes to `other` are lost, so this is only correct while no other thread writes to either wrapper.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn swap_from(&self, other: &Self) {
+        let previous = self.swap(other.load());
+        other.store(previous);
+    }
+
+    /// Loads the interior value of the atomic wrapper.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn load(&self) -> T {
+        self.load_with(load_ordering(O::ORDERING))
+    }
+
+    /// Loads the interior value of the atomic wrapper with the given memory ordering.
+    /// Panics if `ordering` is `Release` or `AcqRel`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn load_with(&self, ordering: Ordering) -> T {
+        debug_assert!(
+            ordering != Ordering::Release,
+            "there is no such thing as a release load"
+        );
+        debug_assert!(
+            ordering != Ordering::AcqRel,
+            "there is no such thing as an acquire-release load"
+        );
+        let out = self.inner.load(ordering);
+        trace_op!("load", bits = out);
+        Self::decode(out)
+    }
+
+    /// Loads the interior value with Ordering::Relaxed.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn load_relaxed(&self) -> T {
+        self.load_with(Ordering::Relaxed)
+    }
+
+    /// Updates the interior value with Ordering::Relaxed.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_relaxed(&self, item: T) {
+        self.store_with(item, Ordering::Relaxed);
+    }
+
+    /// Swaps the interior value with Ordering::Relaxed, returning the previous value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn swap_relaxed(&self, item: T) -> T {
+        self.swap_with(item, Ordering::Relaxed)
+    }
+
+    /// Loads the interior value with Ordering::Acquire, pairing with `store_release` on the producer side.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn load_acquire(&self) -> T {
+        self.load_with(Ordering::Acquire)
+    }
+
+    /// Updates the interior value with Ordering::Release, pairing with `load_acquire` on the consumer side.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_release(&self, item: T) {
+        self.store_with(item, Ordering::Release);
+    }
+
+    /// Swaps the interior value with Ordering::AcqRel, returning the previous value.
+    /// The load half is Acquire and the store half is Release.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn swap_acqrel(&self, item: T) -> T {
+        self.swap_with(item, Ordering::AcqRel)
+    }
+
+    /// Loads the interior value and returns `f` applied to it, without modifying the stored value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn map<U, F: FnOnce(T) -> U>(&self, f: F) -> U {
+        f(self.load())
+    }
+
+    /// Loads the interior value, first checking the raw backing integer with `valid`.
+    /// Returns `InvalidBits` instead of reinterpreting a bit pattern that `valid` rejects, so T may have invalid bit patterns,
+    /// such as a fieldless enum, as long as `valid` accepts only the valid ones.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn try_load<F: FnOnce(BitsOf<BYTES>) -> bool>(
+        &self,
+        valid: F,
+    ) -> Result<T, InvalidBits<BitsOf<BYTES>>> {
+        let bits = self.to_bits();
+        if valid(bits) {
+            Ok(Self::decode(bits))
+        } else {
+            Err(InvalidBits { bits })
+        }
+    }
+
+    /// Stores a value into the atomic integer if the current value is the same as the current value.
+    /// The return value is a result indicating whether the new value was written and containing the previous value.
+    /// On success this value is guaranteed to be equal to current.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn compare_exchange(&self, current: T, new: T) -> Result<T, T> {
+        self.compare_exchange_with(current, new, O::ORDERING, load_ordering(O::ORDERING))
+    }
+
+    /// Same as compare_exchange, but with separate memory orderings for the success and failure paths.
+    /// `success` is used for the read-modify-write when the comparison succeeds, `failure` for the load when it fails.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn compare_exchange_with(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        let (current, new) = (Self::encode(current), Self::encode(new));
+        let out = self.inner.compare_exchange(current, new, success, failure);
+        trace_op!(
+            "compare_exchange",
+            current = current,
+            new = new,
+            success = out.is_ok(),
+        );
+        out.map(Self::decode).map_err(Self::decode)
+    }
+
+    /// Stores a value into the atomic integer if the current value is the same as the current value.
+    /// Unlike compare_exchange, this function is allowed to spuriously fail even when the comparison succeeds, which can result in more efficient code on some platforms.
+    /// The return value is a result indicating whether the new value was written and containing the previous value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn compare_exchange_weak(&self, current: T, new: T) -> Result<T, T> {
+        let (current, new) = (Self::encode(current), Self::encode(new));
+        let out =
+            self.inner
+                .compare_exchange_weak(current, new, O::ORDERING, load_ordering(O::ORDERING));
+        trace_op!(
+            "compare_exchange_weak",
+            current = current,
+            new = new,
+            success = out.is_ok(),
+        );
+        out.map(Self::decode).map_err(Self::decode)
+    }
+
+    /// Stores `new` if the current value is the same as `current`, returning whether the exchange happened.
+    /// A convenience over `compare_exchange(current, new).is_ok()` for callers that do not need the previous value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn compare_and_swap(&self, current: T, new: T) -> bool {
+        self.compare_exchange(current, new).is_ok()
+    }
+
+    /// Replaces `sentinel` with `value`, for one-shot initialization of a slot that starts out as `sentinel`.
+    /// Returns `Err(current)` with the value stored by whichever thread initialized it first.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn init_if(&self, sentinel: T, value: T) -> Result<(), T> {
+        self.compare_exchange(sentinel, value).map(|_| ())
+    }
+
+    /// Same as compare_exchange, but returns the witnessed value and whether the exchange happened as a struct,
+    /// for callers that want the previous value regardless of the outcome.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn compare_exchange_detailed(&self, current: T, new: T) -> CasOutcome<T> {
+        match self.compare_exchange(current, new) {
+            Ok(previous) => CasOutcome {
+                success: true,
+                previous,
+            },
+            Err(previous) => CasOutcome {
+                success: false,
+                previous,
+            },
+        }
+    }
+
+    /// Fetches the value, and applies a function to it that returns an optional new value.
+    /// Returns `Ok(previous)` if the function returned `Some(_)`, else `Err(current)`.
+    /// The function may be called multiple times if the value has been changed from other threads in the meantime,
+    /// as long as the function returns `Some(_)`, but it will only have been applied once to the stored value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_update<F: FnMut(T) -> Option<T>>(&self, f: F) -> Result<T, T> {
+        self.fetch_update_with(O::ORDERING, load_ordering(O::ORDERING), f)
+    }
+
+    /// Same as fetch_update, but with explicit memory orderings, matching the signature of `AtomicU64::fetch_update` and the other atomic integers.
+    /// `set_order` is used for the compare-exchange that stores the new value, `fetch_order` for the loads of the current value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_update_with<F: FnMut(T) -> Option<T>>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<T, T> {
+        let mut backoff = Backoff::new();
+        let mut current = self.load_with(fetch_order);
+        while let Some(new) = f(current) {
+            let (current_bits, new_bits) = (Self::encode(current), Self::encode(new));
+            let out =
+                self.inner
+                    .compare_exchange_weak(current_bits, new_bits, set_order, fetch_order);
+            trace_op!(
+                "compare_exchange_weak",
+                current = current_bits,
+                new = new_bits,
+                success = out.is_ok(),
+            );
+            match out {
+                Ok(previous) => return Ok(Self::decode(previous)),
+                Err(actual) => current = Self::decode(actual),
+            }
+            backoff.spin();
+        }
+        Err(current)
+    }
+
+    /// Same as fetch_update, but gives up after `max_retries` compare-exchanges have failed because the value changed concurrently,
+    /// as a bail-out point for latency-sensitive code under heavy contention.
+    /// Returns `Ok(previous)` if a new value was stored, and otherwise an error telling a rejection by `f` apart from running out of retries.
+    /// It uses the strong compare_exchange, so spurious failures do not count against `max_retries`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_update_bounded<F: FnMut(T) -> Option<T>>(
+        &self,
+        max_retries: usize,
+        mut f: F,
+    ) -> Result<T, FetchUpdateError<T>> {
+        let mut backoff = Backoff::new();
+        let mut current = self.load();
+        let mut retries = 0;
+        loop {
+            let new = f(current).ok_or(FetchUpdateError::Rejected(current))?;
+            match self.compare_exchange(current, new) {
+                Ok(previous) => return Ok(previous),
+                Err(actual) => current = actual,
+            }
+            if retries == max_retries {
+                return Err(FetchUpdateError::RetriesExceeded(current));
+            }
+            retries += 1;
+            backoff.spin();
+        }
+    }
+
+    /// Same as fetch_update, but the function also returns auxiliary data computed alongside the new value.
+    /// Returns `Ok((previous, aux))` with the data from the invocation whose value was stored, else `Err(current)`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_update_aux<A, F: FnMut(T) -> Option<(T, A)>>(
+        &self,
+        mut f: F,
+    ) -> Result<(T, A), T> {
+        let mut backoff = Backoff::new();
+        let mut current = self.load();
+        while let Some((new, aux)) = f(current) {
+            match self.compare_exchange_weak(current, new) {
+                Ok(previous) => return Ok((previous, aux)),
+                Err(actual) => current = actual,
+            }
+            backoff.spin();
+        }
+        Err(current)
+    }
+
+    /// Stores `new` if `pred` holds for the current value, retrying if the value changes concurrently.
+    /// Returns `Ok(previous)` if `new` was stored, else `Err(current)` with the value that failed the predicate.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_if<F: Fn(T) -> bool>(&self, new: T, pred: F) -> Result<T, T> {
+        self.fetch_update(|current| pred(current).then_some(new))
+    }
+
+    /// Applies a function to the value until it is stored successfully, returning the new value.
+    /// The function may be called multiple times if the value has been changed from other threads in the meantime.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn update<F: FnMut(T) -> T>(&self, mut f: F) -> T {
+        let mut backoff = Backoff::new();
+        let mut current = self.load();
+        loop {
+            let new = f(current);
+            match self.compare_exchange_weak(current, new) {
+                Ok(_) => return new,
+                Err(actual) => current = actual,
+            }
+            backoff.spin();
+        }
+    }
+
+    /// Busy-waits until `pred` holds for the value, returning the matching value.
+    /// The value is polled with Ordering::Relaxed loads and `spin_loop` hints, so waiting does not pay for a stronger ordering on every poll.
+    /// Once a polled value matches, it is confirmed with an Ordering::SeqCst load, which gives the happens-before edge with the store that made `pred` hold.
+    /// Intended for short waits; prefer blocking primitives when the wait may be long.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn spin_until<F: Fn(T) -> bool>(&self, pred: F) -> T {
+        loop {
+            if pred(self.load_with(Ordering::Relaxed)) {
+                let value = self.load_with(Ordering::SeqCst);
+                if pred(value) {
+                    return value;
+                }
+            }
+            spin_loop();
+        }
+    }
+
+    /// Busy-waits while `pred` holds for the value, returning the first value for which it does not.
+    /// The inverse of `spin_until`, with the same ordering behaviour.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn spin_while<F: Fn(T) -> bool>(&self, pred: F) -> T {
+        self.spin_until(|value| !pred(value))
+    }
+
+    /// Consumes the atomic wrapper and returns the contained value.
+    /// This is safe because passing `self` by value guarantees that no other threads are concurrently accessing the atomic data.
+    pub fn into_inner(self) -> T {
+        Self::decode(self.inner.into_inner())
+    }
+
+    /// Returns a guard giving mutable access to the contained value, without atomic operations.
+    /// This requires `&mut self`, so it is only usable where no other thread can reach the wrapper, such as during setup.
+    /// The guard derefs to `&mut T`, and writes through it are visible to subsequent loads once it is dropped.
+    #[cfg(not(feature = "loom"))]
+    pub fn get_mut(&mut self) -> SubatomicMut<'_, T> {
+        SubatomicMut::new(self.get_mut_ref())
+    }
+
+    /// Returns a guard giving mutable access to the contained value, without atomic operations.
+    /// This requires `&mut self`, so it is only usable where no other thread can reach the wrapper, such as during setup.
+    /// The guard works on a copy of the value, which it writes back when dropped.
+    #[cfg(feature = "loom")]
+    pub fn get_mut(&mut self) -> SubatomicMut<'_, T> {
+        let value = self.inner.with_mut(|bits| Self::decode(*bits));
+        SubatomicMut::with_write_back(value, self)
+    }
+
+    /// Stores `item` through exclusive access, for the write-back of the `SubatomicMut` guard.
+    #[cfg(feature = "loom")]
+    pub(crate) fn store_mut(&mut self, item: T) {
+        self.inner.with_mut(|bits| *bits = Self::encode(item));
+    }
+
+    /// Returns a mutable reference to the contained value through exclusive access, without atomic operations.
+    /// Writes through the reference are visible to subsequent loads.
+    /// Not available with the `loom` feature.
+    #[cfg(not(feature = "loom"))]
+    pub fn get_mut_ref(&mut self) -> &mut T {
+        unsafe { &mut *(self.inner.get_mut() as *mut BitsOf<BYTES>).cast::<T>() }
+    }
+
+    /// Returns a mutable pointer to the contained value, for interop with foreign code that performs its own atomic operations.
+    /// The pointer is aligned to BYTES bytes, the alignment of the backing atomic.
+    /// All accesses through it that may race with other accesses to this wrapper must be atomic,
+    /// and any value written through it must be a valid T.
+    /// Not available with the `loom` feature.
+    #[cfg(not(feature = "loom"))]
+    pub fn as_ptr(&self) -> *mut T {
+        self.inner.as_ptr().cast::<T>()
+    }
+
+    /// Loads the raw backing integer, bypassing the conversion to T.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn to_bits(&self) -> BitsOf<BYTES> {
+        let out = self.inner.load(load_ordering(O::ORDERING));
+        trace_op!("load", bits = out);
+        out
+    }
+
+    /// Loads the raw backing integer for logging, with a Debug impl that prints it in hex and does not require T: Debug.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn debug_bits(&self) -> RawBits<BitsOf<BYTES>> {
+        RawBits(self.to_bits())
+    }
+
+    /// Loads the raw backing integer for logging, with a Display impl that prints it in hex and does not require T: Display.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn display_bits(&self) -> RawBits<BitsOf<BYTES>> {
+        RawBits(self.to_bits())
+    }
+
+    /// Consumes the atomic wrapper and returns the backing atomic integer, holding the raw bits of the current value.
+    pub fn into_atomic(self) -> AtomicOf<BYTES> {
+        self.inner
+    }
+}
+
+/// The constructors other than `with_ordering` create wrappers with the crate's default ordering, so the ordering parameter can be inferred.
+impl<T: Copy + 'static, const BYTES: usize> SubatomicN<T, BYTES>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Create a new atomic wrapper
+    ///
+    /// A T that is not exactly BYTES bytes fails to build. The check fires after monomorphization,
+    /// so `cargo check` accepts e.g. `Subatomic64::new(1u32)`, and only `cargo build` rejects it.
+    pub fn new(item: T) -> Self {
+        Self::from_value(item)
+    }
+
+    /// Create a new atomic wrapper from an existing atomic integer, whose raw value is reinterpreted as T.
+    /// The atomic type follows the backend selected by the `portable-atomic` and `loom` features.
+    ///
+    /// # Safety
+    /// The integer held by `inner` must be a valid bit pattern for T, e.g. not zero for a `NonZeroU64`,
+    /// as every load reinterprets it as T without a check.
+    pub unsafe fn from_atomic(inner: AtomicOf<BYTES>) -> Self {
+        let () = Self::SIZE_CHECK;
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a new atomic wrapper, checking the size of T at runtime instead of when building.
+    /// Returns an error reporting the expected and actual sizes if T is not exactly BYTES bytes.
+    pub fn try_new(item: T) -> Result<Self, SizeMismatch> {
+        let expected = BYTES;
+        let actual = core::mem::size_of::<T>();
+        if actual != expected {
+            return Err(SizeMismatch { expected, actual });
+        }
+        Ok(Self {
+            inner: AtomicBacking::new(Self::encode(item)),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Create a new atomic wrapper for a T smaller than BYTES bytes, storing it in the first bytes of the backing integer and zeroing the rest.
+    /// Every operation then works on the backing integer with those padding bytes zero, so comparisons only consider the bytes of T.
+    /// Arithmetic and bitwise operations act on the whole backing integer and could carry into the padding bytes,
+    /// so they check that T fills the backing integer and fail to compile on a padded wrapper, as do the `zerocopy` conversions.
+    pub fn new_padded(item: T) -> Self {
+        let () = Self::PADDED_SIZE_CHECK;
+        Self {
+            inner: AtomicBacking::new(Self::encode(item)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy + Default + 'static, const BYTES: usize, O: DefaultOrdering> SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Swaps in `T::default()`, returning the previous value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn take(&self) -> T {
+        self.swap(T::default())
+    }
+}
+
+impl<const BYTES: usize, O: DefaultOrdering> SubatomicN<[u8; BYTES], BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Loads the bytes, in the order they were stored.
+    /// Byte arrays round-trip unchanged on every target, but the backing integer seen by `to_bits` holds them in native byte order,
+    /// e.g. `u64::from_ne_bytes(bytes)` for 8 bytes, so its value differs between little- and big-endian targets.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn load_bytes(&self) -> [u8; BYTES] {
+        self.load()
+    }
+
+    /// Stores the bytes, replacing all of them at once.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_bytes(&self, bytes: [u8; BYTES]) {
+        self.store(bytes);
+    }
+}
+
+impl<T: Copy + PartialEq + 'static, const BYTES: usize, O: DefaultOrdering> SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Loads the interior value, returning it only if it differs from `last`.
+    /// This is the polling side of a flag loop: call it with the last value seen until it returns `Some`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn try_load_changed(&self, last: T) -> Option<T> {
+        let current = self.load();
+        (current != last).then_some(current)
+    }
+
+    /// Like `try_load_changed`, but loads with Ordering::Relaxed.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn try_load_changed_relaxed(&self, last: T) -> Option<T> {
+        let current = self.load_relaxed();
+        (current != last).then_some(current)
+    }
+}
+
+impl<T: Arithmetic + 'static, const BYTES: usize, O: DefaultOrdering> SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Returns a reference to the backing atomic integer, for code that operates on raw atomics.
+    /// Operations through it bypass the conversion to and from T, which is only sound because every bit pattern is a valid integer T.
+    /// It is the atomic of the enabled backend, e.g. `portable_atomic::AtomicU64` for an 8-byte wrapper with the `portable-atomic` feature.
+    pub fn inner(&self) -> &AtomicOf<BYTES> {
+        let () = Self::SIZE_CHECK;
+        &self.inner
+    }
+
+    /// Stores the raw backing integer, bypassing the conversion from T.
+    /// Only available for integer T, for which every bit pattern is a valid value, so later loads cannot see an invalid T.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_bits(&self, bits: BitsOf<BYTES>) {
+        let () = Self::SIZE_CHECK;
+        self.inner.store(bits, store_ordering(O::ORDERING));
+        trace_op!("store", bits = bits);
+    }
+
+    /// Swaps in zero, returning the previous value, e.g. to drain a bitmask of pending events.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn clear(&self) -> T {
+        let () = Self::SIZE_CHECK;
+        let out = self.inner.swap(BitsOf::<BYTES>::ZERO, O::ORDERING);
+        trace_op!("swap", bits = BitsOf::<BYTES>::ZERO, previous = out);
+        Self::decode(out)
+    }
+
+    /// Bitwise "and" with the current value, returning the previous value.
+    /// Adds to the current value, returning the previous value.
+    /// This operation wraps around on overflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_add(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_add(bits, O::ORDERING);
+        trace_op!("fetch_add", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Subtracts from the current value, returning the previous value.
+    /// This operation wraps around on underflow, e.g. subtracting 1 from an unsigned 0 stores the maximum value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_sub(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_sub(bits, O::ORDERING);
+        trace_op!("fetch_sub", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Adds to the current value with Ordering::Relaxed, returning the previous value.
+    /// This operation wraps around on overflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_add_relaxed(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_add(bits, Ordering::Relaxed);
+        trace_op!("fetch_add", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Subtracts from the current value with Ordering::Relaxed, returning the previous value.
+    /// This operation wraps around on underflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_sub_relaxed(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_sub(bits, Ordering::Relaxed);
+        trace_op!("fetch_sub", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Adds to the current value with a single fetch_add, returning `(previous, new)`.
+    /// This operation wraps around on overflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn add_returning(&self, val: T) -> (T, T) {
+        let () = Self::SIZE_CHECK;
+        let previous = self.fetch_add(val);
+        (previous, previous.wrapping_add(val))
+    }
+
+    /// Subtracts from the current value with a single fetch_sub, returning `(previous, new)`.
+    /// This operation wraps around on underflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn sub_returning(&self, val: T) -> (T, T) {
+        let () = Self::SIZE_CHECK;
+        let previous = self.fetch_sub(val);
+        (previous, previous.wrapping_sub(val))
+    }
+
+    /// Adds one to the current value, returning the new value.
+    /// This operation wraps around on overflow, like fetch_add.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn increment(&self) -> T {
+        let () = Self::SIZE_CHECK;
+        self.fetch_add(T::ONE).wrapping_add(T::ONE)
+    }
+
+    /// Subtracts one from the current value, returning the new value.
+    /// This operation wraps around on underflow, like fetch_sub.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn decrement(&self) -> T {
+        let () = Self::SIZE_CHECK;
+        self.fetch_sub(T::ONE).wrapping_sub(T::ONE)
+    }
+
+    /// Rotates the bits of the current value left by `n`, returning the new value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn rotate_left(&self, n: u32) -> T {
+        let () = Self::SIZE_CHECK;
+        self.rotate_bits(|bits| bits.rotate_left(n))
+    }
+
+    /// Rotates the bits of the current value right by `n`, returning the new value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn rotate_right(&self, n: u32) -> T {
+        let () = Self::SIZE_CHECK;
+        self.rotate_bits(|bits| bits.rotate_right(n))
+    }
+
+    /// Applies a rotation of the backing integer in a compare-exchange loop, returning the new value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    fn rotate_bits(&self, rotate: impl Fn(BitsOf<BYTES>) -> BitsOf<BYTES>) -> T {
+        let mut backoff = Backoff::new();
+        let mut current = self.inner.load(load_ordering(O::ORDERING));
+        trace_op!("load", bits = current);
+        loop {
+            let new = rotate(current);
+            let out = self.inner.compare_exchange_weak(
+                current,
+                new,
+                O::ORDERING,
+                load_ordering(O::ORDERING),
+            );
+            trace_op!(
+                "compare_exchange_weak",
+                current = current,
+                new = new,
+                success = out.is_ok(),
+            );
+            match out {
+                Ok(_) => return Self::decode(new),
+                Err(actual) => current = actual,
+            }
+            backoff.spin();
+        }
+    }
+
+    /// Adds to the current value, returning the previous value.
+    /// This operation saturates at the maximum value of T instead of wrapping, using a compare-exchange loop.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_add_saturating(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        match self.fetch_update(|current| Some(current.saturating_add(val))) {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+
+    /// Subtracts from the current value, returning the previous value.
+    /// This operation saturates at the minimum value of T instead of wrapping, using a compare-exchange loop.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_sub_saturating(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        match self.fetch_update(|current| Some(current.saturating_sub(val))) {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+
+    /// Adds to the current value, returning the new value, or `None` without modifying the value if the addition would overflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn checked_add(&self, val: T) -> Option<T> {
+        let () = Self::SIZE_CHECK;
+        let mut new = None;
+        let _ = self.fetch_update(|current| {
+            new = current.checked_add(val);
+            new
+        });
+        new
+    }
+
+    /// Subtracts from the current value, returning the new value, or `None` without modifying the value if the subtraction would overflow.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn checked_sub(&self, val: T) -> Option<T> {
+        let () = Self::SIZE_CHECK;
+        let mut new = None;
+        let _ = self.fetch_update(|current| {
+            new = current.checked_sub(val);
+            new
+        });
+        new
+    }
+
+    /// Adds to the current value, returning the previous value.
+    /// The same as fetch_add, for call sites that want to spell out the wrapping behaviour.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_add_wrapping(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        self.fetch_add(val)
+    }
+
+    /// Subtracts from the current value, returning the previous value.
+    /// The same as fetch_sub, for call sites that want to spell out the wrapping behaviour.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_sub_wrapping(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        self.fetch_sub(val)
+    }
+}
+
+impl<T: BitOps + 'static, const BYTES: usize, O: DefaultOrdering> SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_and(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_and(bits, O::ORDERING);
+        trace_op!("fetch_and", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Bitwise "nand" with the current value, returning the previous value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_nand(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_nand(bits, O::ORDERING);
+        trace_op!("fetch_nand", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Bitwise "or" with the current value, returning the previous value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_or(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_or(bits, O::ORDERING);
+        trace_op!("fetch_or", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Bitwise "xor" with the current value, returning the previous value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_xor(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_xor(bits, O::ORDERING);
+        trace_op!("fetch_xor", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Maximum with the current value, returning the previous value.
+    /// Only available for unsigned integers, since the comparison is performed on the raw bits and would not match the ordering of arbitrary `T`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_max(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_max(bits, O::ORDERING);
+        trace_op!("fetch_max", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Minimum with the current value, returning the previous value.
+    /// Only available for unsigned integers, since the comparison is performed on the raw bits and would not match the ordering of arbitrary `T`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn fetch_min(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_min(bits, O::ORDERING);
+        trace_op!("fetch_min", bits = bits, previous = out);
+        Self::decode(out)
+    }
+
+    /// Raises the current value to `val` if it is lower, returning the new value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_max(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_max(bits, O::ORDERING);
+        trace_op!("fetch_max", bits = bits, previous = out);
+        Self::decode(cmp::max(out, bits))
+    }
+
+    /// Lowers the current value to `val` if it is higher, returning the new value.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_min(&self, val: T) -> T {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode(val);
+        let out = self.inner.fetch_min(bits, O::ORDERING);
+        trace_op!("fetch_min", bits = bits, previous = out);
+        Self::decode(cmp::min(out, bits))
+    }
+
+    /// Mask selecting bit `index` of the backing integer.
+    fn bit_mask(index: u32) -> BitsOf<BYTES> {
+        debug_assert!(index < BitsOf::<BYTES>::BITS, "bit index out of range");
+        BitsOf::<BYTES>::ONE << index
+    }
+
+    /// Sets bit `index` of the value, returning whether it was previously set.
+    /// Panics in debug builds if `index` is not less than the number of bits in T.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn set_bit(&self, index: u32) -> bool {
+        let () = Self::SIZE_CHECK;
+        let mask = Self::bit_mask(index);
+        let out = self.inner.fetch_or(mask, O::ORDERING);
+        trace_op!("fetch_or", bits = mask, previous = out);
+        out & mask != BitsOf::<BYTES>::ZERO
+    }
+
+    /// Clears bit `index` of the value, returning whether it was previously set.
+    /// Panics in debug builds if `index` is not less than the number of bits in T.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn clear_bit(&self, index: u32) -> bool {
+        let () = Self::SIZE_CHECK;
+        let mask = Self::bit_mask(index);
+        let out = self.inner.fetch_and(!mask, O::ORDERING);
+        trace_op!("fetch_and", bits = !mask, previous = out);
+        out & mask != BitsOf::<BYTES>::ZERO
+    }
+
+    /// Flips bit `index` of the value, returning whether it was previously set.
+    /// Panics in debug builds if `index` is not less than the number of bits in T.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn toggle_bit(&self, index: u32) -> bool {
+        let () = Self::SIZE_CHECK;
+        let mask = Self::bit_mask(index);
+        let out = self.inner.fetch_xor(mask, O::ORDERING);
+        trace_op!("fetch_xor", bits = mask, previous = out);
+        out & mask != BitsOf::<BYTES>::ZERO
+    }
+
+    /// Returns whether bit `index` of the value is set, without modifying it.
+    /// Panics in debug builds if `index` is not less than the number of bits in T.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn test_bit(&self, index: u32) -> bool {
+        let () = Self::SIZE_CHECK;
+        self.to_bits() & Self::bit_mask(index) != BitsOf::<BYTES>::ZERO
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: Copy + bytemuck::NoUninit + 'static, const BYTES: usize> SubatomicN<T, BYTES>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Create a new atomic wrapper, checking at compile time that T has no padding or other uninitialized bytes.
+    pub fn new_no_uninit(item: T) -> Self {
+        Self::new(item)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: Copy + bytemuck::Pod + 'static, const BYTES: usize> SubatomicN<T, BYTES>
+where
+    Bytes<BYTES>: SupportedWidth,
+    BitsOf<BYTES>: bytemuck::Pod,
+{
+    /// Create a new atomic wrapper from a `Pod` value, converting it with `bytemuck::cast` rather than `transmute_copy`.
+    /// Since `Pod` types have no padding and accept every bit pattern, every other operation on the wrapper is sound as well.
+    pub fn from_pod(item: T) -> Self {
+        let () = Self::SIZE_CHECK;
+        Self {
+            inner: AtomicBacking::new(bytemuck::cast(item)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: Copy + bytemuck::Zeroable + 'static, const BYTES: usize, O: DefaultOrdering>
+    SubatomicN<T, BYTES, O>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Stores the all-zero bit pattern of T, e.g. to reset a wrapper over a struct of integers.
+    /// The `Zeroable` bound guarantees that zero is a valid bit pattern for T.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn clear_bits(&self) {
+        self.inner
+            .store(BitsOf::<BYTES>::ZERO, store_ordering(O::ORDERING));
+        trace_op!("store", bits = BitsOf::<BYTES>::ZERO);
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<T, const BYTES: usize, O: DefaultOrdering> SubatomicN<T, BYTES, O>
+where
+    T: Copy + zerocopy::IntoBytes + zerocopy::FromBytes + zerocopy::Immutable + 'static,
+    Bytes<BYTES>: SupportedWidth,
+    BitsOf<BYTES>: zerocopy::IntoBytes + zerocopy::FromBytes + zerocopy::Immutable,
+{
+    fn encode_zc(item: T) -> BitsOf<BYTES> {
+        zerocopy::FromBytes::read_from_bytes(item.as_bytes()).expect("size mismatch")
+    }
+
+    fn decode_zc(bits: BitsOf<BYTES>) -> T {
+        zerocopy::FromBytes::read_from_bytes(zerocopy::IntoBytes::as_bytes(&bits))
+            .expect("size mismatch")
+    }
+
+    /// Loads the interior value of the atomic wrapper, converting it with `zerocopy`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn to_zc(&self) -> T {
+        let () = Self::SIZE_CHECK;
+        let out = self.inner.load(load_ordering(O::ORDERING));
+        trace_op!("load", bits = out);
+        Self::decode_zc(out)
+    }
+
+    /// Update the interior value of the atomic wrapper, converting it with `zerocopy`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store_zc(&self, item: T) {
+        let () = Self::SIZE_CHECK;
+        let bits = Self::encode_zc(item);
+        self.inner.store(bits, store_ordering(O::ORDERING));
+        trace_op!("store", bits = bits);
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<T, const BYTES: usize> SubatomicN<T, BYTES>
+where
+    T: Copy + zerocopy::IntoBytes + zerocopy::FromBytes + zerocopy::Immutable + 'static,
+    Bytes<BYTES>: SupportedWidth,
+    BitsOf<BYTES>: zerocopy::IntoBytes + zerocopy::FromBytes + zerocopy::Immutable,
+{
+    /// Create a new atomic wrapper, converting `item` with `zerocopy` rather than `transmute_copy`.
+    /// The bounds guarantee at compile time that every bit pattern of the backing integer is a valid T.
+    pub fn from_zc(item: T) -> Self {
+        let () = Self::SIZE_CHECK;
+        Self {
+            inner: AtomicBacking::new(Self::encode_zc(item)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Only available for integer T, for which every bit pattern of the backing integer is a valid value,
+/// since the bits are reinterpreted as T without a check.
+#[cfg(feature = "loom")]
+impl<T: Arithmetic + 'static, const BYTES: usize> SubatomicN<T, BYTES>
+where
+    Bytes<BYTES>: SupportedWidth,
+{
+    /// Create a new atomic wrapper directly from the raw backing integer.
+    /// Not `const` with the `loom` feature, as loom atomics cannot be created in const contexts.
+    pub fn from_bits(bits: BitsOf<BYTES>) -> Self {
+        Self::from_raw(bits)
+    }
+}
+
+/// Implements the `const` raw-bit constructor of each width, as the generic `AtomicBacking::new` cannot be called in const contexts.
+/// It is only available for integer T, for which every bit pattern of the backing integer is a valid value,
+/// since the bits are reinterpreted as T without a check.
+#[cfg(not(feature = "loom"))]
+#[allow(unused_macros)]
+macro_rules! impl_const_from_bits {
+    ($($bytes:literal => $atomic:ty => $bits:ty),*) => {
+        $(
+            impl<T: Arithmetic + 'static> SubatomicN<T, $bytes> {
+                /// Create a new atomic wrapper directly from the raw backing integer, usable in const contexts such as `static` initializers.
+                pub const fn from_bits(bits: $bits) -> Self {
+                    let () = Self::SIZE_CHECK;
+                    Self {
+                        inner: <$atomic>::new(bits),
+                        _phantom: PhantomData,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(all(not(feature = "loom"), subatomic_has_8))]
+impl_const_from_bits!(1 => AtomicU8 => u8);
+#[cfg(all(not(feature = "loom"), subatomic_has_16))]
+impl_const_from_bits!(2 => AtomicU16 => u16);
+#[cfg(all(not(feature = "loom"), subatomic_has_32))]
+impl_const_from_bits!(4 => AtomicU32 => u32);
+#[cfg(all(not(feature = "loom"), subatomic_has_64))]
+impl_const_from_bits!(8 => AtomicU64 => u64);
+#[cfg(all(not(feature = "loom"), feature = "u128", subatomic_has_128))]
+impl_const_from_bits!(16 => AtomicU128 => u128);
+
+#[cfg(all(test, not(feature = "loom"), feature = "u32", feature = "u64"))]
+mod tests {
+    extern crate std;
+
+    use core::mem::{align_of, size_of};
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    use super::{AtomicOf, SubatomicN};
+    use crate::ordering::{Relaxed, SeqCst};
+    use crate::{Subatomic32, Subatomic64};
+
+    /// A pointer asserted to be Send, as a pointer T needs to be for the wrapper to be Send and Sync.
+    #[allow(dead_code)]
+    #[derive(Clone, Copy)]
+    struct SendPtr(*mut u8);
+
+    unsafe impl Send for SendPtr {}
+
+    static_assertions::assert_impl_all!(Subatomic64<u64>: Send, Sync);
+    static_assertions::assert_impl_all!(Subatomic64<SendPtr>: Send, Sync);
+    static_assertions::assert_not_impl_any!(Subatomic64<*mut u8>: Send, Sync);
+
+    #[test]
+    fn layout_matches_backing_atomic() {
+        assert_eq!(size_of::<SubatomicN<u64, 8>>(), 8);
+        assert_eq!(size_of::<SubatomicN<u64, 8>>(), size_of::<AtomicOf<8>>());
+        assert_eq!(align_of::<SubatomicN<u64, 8>>(), align_of::<AtomicOf<8>>());
+        assert_eq!(size_of::<SubatomicN<[u8; 8], 8, Relaxed>>(), 8);
+        assert_eq!(align_of::<SubatomicN<[u8; 8], 8, SeqCst>>(), 8);
+        assert_eq!(size_of::<SubatomicN<u32, 4>>(), size_of::<AtomicOf<4>>());
+        assert_eq!(align_of::<SubatomicN<u16, 2>>(), align_of::<AtomicOf<2>>());
+        assert_eq!(size_of::<SubatomicN<u8, 1>>(), 1);
+    }
+
+    #[test]
+    fn fetch_nand_matches_atomic_u64() {
+        let cases = [
+            (0u64, 0u64),
+            (u64::MAX, 0),
+            (0, u64::MAX),
+            (u64::MAX, u64::MAX),
+            (0xdead_beef_0123_4567, 0xffff_0000_ffff_0000),
+        ];
+        for (initial, val) in cases {
+            let std = AtomicU64::new(initial);
+            let ours = Subatomic64::new(initial);
+            assert_eq!(ours.fetch_nand(val), std.fetch_nand(val, Ordering::SeqCst));
+            assert_eq!(ours.load(), std.load(Ordering::SeqCst));
+        }
+    }
+
+    #[test]
+    fn update_from_many_threads() {
+        const THREADS: u64 = 8;
+        const ITERATIONS: u64 = 10_000;
+        let value = Subatomic64::new(0u64);
+        std::thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..ITERATIONS {
+                        value.update(|v| v + 1);
+                    }
+                });
+            }
+        });
+        assert_eq!(value.load(), THREADS * ITERATIONS);
+    }
+
+    #[test]
+    fn get_mut_is_visible_to_load() {
+        let mut value = Subatomic64::new(1u64);
+        *value.get_mut() += 41;
+        assert_eq!(value.load(), 42);
+        *value.get_mut_ref() = 7;
+        assert_eq!(value.load(), 7);
+    }
+
+    #[test]
+    fn fetch_update_with_matches_atomic_u64() {
+        let std = AtomicU64::new(10);
+        let ours = Subatomic64::new(10u64);
+        let (set, fetch) = (Ordering::AcqRel, Ordering::Acquire);
+        let double = |v: u64| v.checked_mul(2);
+        let reject = |_: u64| None;
+        assert_eq!(
+            ours.fetch_update_with(set, fetch, double),
+            std.fetch_update(set, fetch, double),
+        );
+        assert_eq!(
+            ours.fetch_update_with(set, fetch, reject),
+            std.fetch_update(set, fetch, reject),
+        );
+        assert_eq!(ours.load(), std.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_bit_at_both_ends() {
+        let value = Subatomic64::new(0u64);
+        assert!(!value.test_bit(0));
+        assert!(!value.test_bit(63));
+        assert!(!value.set_bit(0));
+        assert!(value.test_bit(0));
+        assert!(!value.test_bit(63));
+        assert!(!value.set_bit(63));
+        assert!(value.test_bit(63));
+        assert_eq!(value.load(), 1 | 1 << 63);
+        assert!(value.clear_bit(0));
+        assert!(!value.test_bit(0));
+        assert!(value.test_bit(63));
+    }
+
+    #[cfg(all(feature = "u128", subatomic_has_128))]
+    #[test]
+    fn treiber_stack_on_subatomic128() {
+        use crate::Subatomic128;
+
+        const NIL: u64 = u64::MAX;
+        const NODES: usize = 64;
+
+        /// Stack of node indices, with a `(top, tag)` head whose tag is bumped by every push and pop to defeat ABA.
+        /// The orderings are named, so the stack stays correct with `relaxed-default`.
+        struct Stack {
+            head: Subatomic128<(u64, u64), SeqCst>,
+            next: [Subatomic64<u64, SeqCst>; NODES],
+        }
+
+        impl Stack {
+            fn push(&self, index: u64) {
+                let mut head = self.head.load();
+                loop {
+                    self.next[index as usize].store(head.0);
+                    match self
+                        .head
+                        .compare_exchange(head, (index, head.1.wrapping_add(1)))
+                    {
+                        Ok(_) => return,
+                        Err(actual) => head = actual,
+                    }
+                }
+            }
+
+            fn pop(&self) -> Option<u64> {
+                let mut head = self.head.load();
+                while head.0 != NIL {
+                    let next = self.next[head.0 as usize].load();
+                    match self
+                        .head
+                        .compare_exchange(head, (next, head.1.wrapping_add(1)))
+                    {
+                        Ok(_) => return Some(head.0),
+                        Err(actual) => head = actual,
+                    }
+                }
+                None
+            }
+        }
+
+        let stack = Stack {
+            head: Subatomic128::with_ordering((NIL, 0), SeqCst),
+            next: core::array::from_fn(|_| Subatomic64::with_ordering(NIL, SeqCst)),
+        };
+        for index in 0..NODES as u64 {
+            stack.push(index);
+        }
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..10_000 {
+                        if let Some(index) = stack.pop() {
+                            stack.push(index);
+                        }
+                    }
+                });
+            }
+        });
+        let mut seen = 0u64;
+        while let Some(index) = stack.pop() {
+            assert_eq!(seen & 1 << index, 0, "node {index} popped twice");
+            seen |= 1 << index;
+        }
+        assert_eq!(seen, u64::MAX);
+    }
+
+    #[test]
+    fn byte_arrays_round_trip() {
+        let bytes = [1u8, 2, 3, 4, 5, 6, 7, 0xff];
+        let value = Subatomic64::new(bytes);
+        assert_eq!(value.load(), bytes);
+        assert_eq!(value.to_bits(), u64::from_ne_bytes(bytes));
+        value.store_bytes([0xaa; 8]);
+        assert_eq!(value.load_bytes(), [0xaa; 8]);
+        assert_eq!(value.swap(bytes), [0xaa; 8]);
+        assert_eq!(value.load_bytes(), bytes);
+
+        let short = Subatomic32::new([9u8, 8, 7, 6]);
+        assert_eq!(short.load_bytes(), [9, 8, 7, 6]);
+    }
+
+    #[test]
+    fn clear_signed_integers() {
+        let value = Subatomic64::new(-5i64);
+        assert_eq!(value.clear(), -5);
+        assert_eq!(value.load(), 0);
+        let value = Subatomic32::new(i32::MIN);
+        assert_eq!(value.clear(), i32::MIN);
+        assert_eq!(value.load(), 0);
+    }
+}
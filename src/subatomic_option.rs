@@ -0,0 +1,109 @@
+use core::fmt;
+
+use crate::{Niche, Subatomic};
+
+/// Wraps an `Option<T>` in an atomic, storing `None` in the niche of T so the option fits in an atomic of the size of T.
+/// Both variants round-trip through every operation, all operations are Ordering::SeqCst.
+///
+/// It is built on `Subatomic`, so like it the wrapper always occupies 8 bytes, e.g. 8 for `SubatomicOption<NonZeroU8>`.
+/// For a wrapper the size of T, use the wrapper of that width, e.g. `Subatomic8<Option<NonZeroU8>>`.
+pub struct SubatomicOption<T: Niche> {
+    inner: Subatomic<Option<T>>,
+}
+
+impl<T: Niche> Default for SubatomicOption<T> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T: Niche + fmt::Debug> fmt::Debug for SubatomicOption<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Option<T> as fmt::Debug>::fmt(&self.load(), f)
+    }
+}
+
+impl<T: Niche> SubatomicOption<T> {
+    /// Create a new atomic option
+    pub fn new(item: Option<T>) -> Self {
+        Self {
+            inner: Subatomic::new(item),
+        }
+    }
+
+    /// Update the interior value of the atomic option
+    pub fn store(&self, item: Option<T>) {
+        self.inner.store(item);
+    }
+
+    /// Swap the interior value of the atomic option, returning the previous value
+    pub fn swap(&self, item: Option<T>) -> Option<T> {
+        self.inner.swap(item)
+    }
+
+    /// Loads the interior value of the atomic option.
+    pub fn load(&self) -> Option<T> {
+        self.inner.load()
+    }
+
+    /// Takes the value out of the atomic option, leaving `None` in its place.
+    pub fn take(&self) -> Option<T> {
+        self.swap(None)
+    }
+
+    /// Returns true if the atomic option currently holds a value.
+    pub fn is_some(&self) -> bool {
+        self.load().is_some()
+    }
+
+    /// Returns true if the atomic option is currently empty.
+    pub fn is_none(&self) -> bool {
+        self.load().is_none()
+    }
+
+    /// Stores a value into the atomic option if the current value is the same as the current value.
+    /// The return value is a result indicating whether the new value was written and containing the previous value.
+    /// On success this value is guaranteed to be equal to current.
+    pub fn compare_exchange(
+        &self,
+        current: Option<T>,
+        new: Option<T>,
+    ) -> Result<Option<T>, Option<T>> {
+        self.inner.compare_exchange(current, new)
+    }
+}
+
+#[cfg(all(test, not(feature = "loom")))]
+mod tests {
+    use core::mem::size_of;
+    use core::num::{NonZeroU64, NonZeroU8};
+    use core::ptr::NonNull;
+
+    use super::SubatomicOption;
+
+    #[test]
+    fn some_and_none_round_trip() {
+        let mut target = 5u8;
+        let ptr = NonNull::from(&mut target);
+        let option = SubatomicOption::new(None);
+        assert_eq!(option.load(), None);
+        option.store(Some(ptr));
+        assert_eq!(option.load(), Some(ptr));
+        assert_eq!(option.take(), Some(ptr));
+        assert!(option.is_none());
+
+        let number = SubatomicOption::new(NonZeroU64::new(u64::MAX));
+        assert_eq!(number.load(), NonZeroU64::new(u64::MAX));
+        number.store(None);
+        assert_eq!(number.load(), None);
+        assert_eq!(number.swap(NonZeroU64::new(1)), None);
+        assert_eq!(number.load(), NonZeroU64::new(1));
+    }
+
+    #[test]
+    fn occupies_eight_bytes() {
+        assert_eq!(size_of::<SubatomicOption<NonZeroU8>>(), 8);
+        assert_eq!(size_of::<SubatomicOption<NonZeroU64>>(), 8);
+        assert_eq!(size_of::<crate::SubatomicN<Option<NonZeroU8>, 1>>(), 1);
+    }
+}
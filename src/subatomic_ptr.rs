@@ -0,0 +1,148 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::{size_of, transmute_copy},
+    sync::atomic::Ordering,
+};
+
+use crate::{
+    atomic::AtomicPtr,
+    ordering::{load_ordering, store_ordering, DefaultOrdering, SeqCst},
+};
+
+/// Wraps a pointer-sized T, such as a raw pointer newtype, in an `AtomicPtr`.
+/// Unlike storing a pointer in `SubatomicUsize`, T is only ever reinterpreted as a pointer, never as an integer,
+/// so the provenance of pointers stored in T is preserved.
+/// Operations without an explicit ordering use the default ordering O, one of the markers in the `ordering` module.
+/// It is `ordering::SeqCst` unless another default was chosen with `with_ordering` or spelled out in the type,
+/// e.g. `SubatomicPtr<T, ordering::AcqRel>`, and unlike the width wrappers it is not changed by the `relaxed-default` feature.
+pub struct SubatomicPtr<T: Copy + 'static, O: DefaultOrdering = SeqCst> {
+    inner: AtomicPtr<()>,
+    _phantom: PhantomData<(T, O)>,
+}
+
+impl<T: Copy + Default + 'static, O: DefaultOrdering> Default for SubatomicPtr<T, O> {
+    fn default() -> Self {
+        Self::from_value(T::default())
+    }
+}
+
+impl<T: Copy + fmt::Debug + 'static, O: DefaultOrdering> fmt::Debug for SubatomicPtr<T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Debug>::fmt(&self.load(), f)
+    }
+}
+
+impl<T: Copy + 'static> SubatomicPtr<T> {
+    /// Create a new atomic wrapper
+    pub fn new(item: T) -> Self {
+        Self::from_value(item)
+    }
+}
+
+impl<T: Copy + 'static, O: DefaultOrdering> SubatomicPtr<T, O> {
+    /// Evaluated per monomorphization by the constructors, so a T that is not pointer-sized fails to compile.
+    const SIZE_CHECK: () = assert!(
+        size_of::<T>() == size_of::<*mut ()>(),
+        "SubatomicPtr requires a pointer-sized T"
+    );
+
+    /// Reinterprets `item` as a pointer.
+    fn encode(item: T) -> *mut () {
+        unsafe { transmute_copy(&item) }
+    }
+
+    /// Reinterprets the backing pointer as `T`.
+    fn decode(ptr: *mut ()) -> T {
+        unsafe { transmute_copy(&ptr) }
+    }
+
+    /// Create a new atomic wrapper whose default ordering is the marker `ordering`, e.g. `SubatomicPtr::with_ordering(ptr, ordering::AcqRel)`.
+    /// Loads and stores clamp it to the closest valid ordering, and compare-exchange fails with the clamped load ordering,
+    /// as for `Subatomic64::with_ordering`.
+    pub fn with_ordering(item: T, _ordering: O) -> Self {
+        Self::from_value(item)
+    }
+
+    /// Wraps `item` with the default ordering of the type.
+    fn from_value(item: T) -> Self {
+        let () = Self::SIZE_CHECK;
+        Self {
+            inner: AtomicPtr::new(Self::encode(item)),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Update the interior value of the atomic wrapper
+    pub fn store(&self, item: T) {
+        self.store_with(item, store_ordering(O::ORDERING));
+    }
+
+    /// Update the interior value of the atomic wrapper with the given memory ordering.
+    /// Panics if `ordering` is `Acquire` or `AcqRel`.
+    pub fn store_with(&self, item: T, ordering: Ordering) {
+        debug_assert!(
+            ordering != Ordering::Acquire,
+            "there is no such thing as an acquire store"
+        );
+        debug_assert!(
+            ordering != Ordering::AcqRel,
+            "there is no such thing as an acquire-release store"
+        );
+        self.inner.store(Self::encode(item), ordering);
+    }
+
+    /// Swap the interior value of the atomic wrapper, returning the previous value
+    pub fn swap(&self, item: T) -> T {
+        Self::decode(self.inner.swap(Self::encode(item), O::ORDERING))
+    }
+
+    /// Loads the interior value of the atomic wrapper.
+    pub fn load(&self) -> T {
+        self.load_with(load_ordering(O::ORDERING))
+    }
+
+    /// Loads the interior value of the atomic wrapper with the given memory ordering.
+    /// Panics if `ordering` is `Release` or `AcqRel`.
+    pub fn load_with(&self, ordering: Ordering) -> T {
+        debug_assert!(
+            ordering != Ordering::Release,
+            "there is no such thing as a release load"
+        );
+        debug_assert!(
+            ordering != Ordering::AcqRel,
+            "there is no such thing as an acquire-release load"
+        );
+        Self::decode(self.inner.load(ordering))
+    }
+
+    /// Stores a value into the atomic pointer if the current value is the same as the current value.
+    /// The return value is a result indicating whether the new value was written and containing the previous value.
+    /// On success this value is guaranteed to be equal to current.
+    pub fn compare_exchange(&self, current: T, new: T) -> Result<T, T> {
+        self.inner
+            .compare_exchange(
+                Self::encode(current),
+                Self::encode(new),
+                O::ORDERING,
+                load_ordering(O::ORDERING),
+            )
+            .map(Self::decode)
+            .map_err(Self::decode)
+    }
+
+    /// Stores a value into the atomic pointer if the current value is the same as the current value.
+    /// Unlike compare_exchange, this function is allowed to spuriously fail even when the comparison succeeds, which can result in more efficient code on some platforms.
+    /// The return value is a result indicating whether the new value was written and containing the previous value.
+    pub fn compare_exchange_weak(&self, current: T, new: T) -> Result<T, T> {
+        self.inner
+            .compare_exchange_weak(
+                Self::encode(current),
+                Self::encode(new),
+                O::ORDERING,
+                load_ordering(O::ORDERING),
+            )
+            .map(Self::decode)
+            .map_err(Self::decode)
+    }
+}
@@ -0,0 +1,21 @@
+//! Tracing of the core atomic operations with the `trace` feature, compiling to nothing otherwise.
+#![allow(unused_macros)]
+
+/// Emits a `tracing` trace event for an atomic operation, with the raw bits involved and the location of the caller,
+/// which is the user's call site when invoked from a method that is `#[track_caller]` with the `trace` feature.
+#[cfg(feature = "trace")]
+macro_rules! trace_op {
+    ($op:literal, $($field:ident = $value:expr),* $(,)?) => {
+        tracing::trace!(
+            target: "subatomic",
+            op = $op,
+            $($field = ?$value,)*
+            location = %core::panic::Location::caller(),
+        )
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+macro_rules! trace_op {
+    ($($tt:tt)*) => {};
+}
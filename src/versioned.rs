@@ -0,0 +1,126 @@
+use core::{fmt, sync::atomic::Ordering};
+
+use crate::{atomic::spin_loop, ordering::SeqCst, Subatomic, Subatomic64};
+
+/// Wraps a T in an atomic alongside a generation that is bumped by every `store` and `swap`, for change detection.
+/// `load_versioned` returns a value together with the generation that wrote it, so a reader can skip recomputation
+/// when the generation has not moved since it last looked.
+///
+/// The version counter works like the one in `SeqLock`: it is odd while a write is in progress, and readers retry until
+/// they observe the same even version before and after loading the value. The value itself is atomic, so it is never torn.
+/// Writers exclude each other by moving the version from even to odd.
+pub struct Versioned<T: Copy + 'static> {
+    version: Subatomic64<u64, SeqCst>,
+    value: Subatomic<T>,
+}
+
+impl<T: Copy + Default + 'static> Default for Versioned<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Copy + fmt::Debug + 'static> fmt::Debug for Versioned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, generation) = self.load_versioned();
+        f.debug_struct("Versioned")
+            .field("value", &value)
+            .field("generation", &generation)
+            .finish()
+    }
+}
+
+/// Ends a write on drop, so a panicking writer does not leave the version odd.
+struct WriteGuard<'a> {
+    version: &'a Subatomic64<u64, SeqCst>,
+    started: u64,
+}
+
+impl Drop for WriteGuard<'_> {
+    fn drop(&mut self) {
+        self.version.store_with(self.started + 2, Ordering::Release);
+    }
+}
+
+impl<T: Copy + 'static> Versioned<T> {
+    /// Create a new versioned atomic at generation 0
+    pub fn new(item: T) -> Self {
+        Self {
+            version: Subatomic64::with_ordering(0, SeqCst),
+            value: Subatomic::new(item),
+        }
+    }
+
+    /// Loads the interior value, without its generation.
+    pub fn load(&self) -> T {
+        self.value.load()
+    }
+
+    /// Loads the interior value together with the generation of the write that stored it.
+    /// The generation starts at 0 and increases by one for every `store` and `swap`.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn load_versioned(&self) -> (T, u64) {
+        loop {
+            let before = self.version.load_with(Ordering::Acquire);
+            if before % 2 == 1 {
+                spin_loop();
+                continue;
+            }
+            let value = self.value.load();
+            if self.version.load_with(Ordering::Acquire) == before {
+                return (value, before / 2);
+            }
+        }
+    }
+
+    /// Returns the current generation.
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn generation(&self) -> u64 {
+        self.load_versioned().1
+    }
+
+    /// Update the interior value, bumping the generation
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn store(&self, item: T) {
+        let _guard = self.begin_write();
+        self.value.store(item);
+    }
+
+    /// Swap the interior value, bumping the generation and returning the previous value
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub fn swap(&self, item: T) -> T {
+        let _guard = self.begin_write();
+        self.value.swap(item)
+    }
+
+    /// Waits for any other writer to finish, then moves the version to odd.
+    #[cfg_attr(feature = "trace", track_caller)]
+    fn begin_write(&self) -> WriteGuard<'_> {
+        let mut started = self.version.load_with(Ordering::Relaxed);
+        loop {
+            if started % 2 == 1 {
+                spin_loop();
+                started = self.version.load_with(Ordering::Relaxed);
+                continue;
+            }
+            match self.version.compare_exchange_with(
+                started,
+                started + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => started = actual,
+            }
+        }
+        WriteGuard {
+            version: &self.version,
+            started,
+        }
+    }
+
+    /// Consumes the versioned atomic and returns the value.
+    pub fn into_inner(self) -> T {
+        self.value.load()
+    }
+}
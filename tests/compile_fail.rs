@@ -0,0 +1,6 @@
+#![cfg(all(not(feature = "loom"), feature = "u64"))]
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "loom")]
+
+use loom::cell::UnsafeCell;
+use loom::sync::Arc;
+use loom::thread;
+use subatomic::ordering::AcqRel;
+use subatomic::Subatomic64;
+
+const EMPTY: u64 = 0;
+const FULL: u64 = 1;
+const TAKEN: u64 = 2;
+
+/// The state names its ordering, so that `relaxed-default` enabled elsewhere in the build cannot weaken the handoff.
+struct Handoff {
+    state: Subatomic64<u64, AcqRel>,
+    data: UnsafeCell<u64>,
+}
+
+unsafe impl Sync for Handoff {}
+
+#[test]
+fn compare_exchange_hands_off_data() {
+    loom::model(|| {
+        let handoff = Arc::new(Handoff {
+            state: Subatomic64::with_ordering(EMPTY, AcqRel),
+            data: UnsafeCell::new(0),
+        });
+
+        let producer = {
+            let handoff = handoff.clone();
+            thread::spawn(move || {
+                handoff.data.with_mut(|data| unsafe { *data = 42 });
+                assert_eq!(handoff.state.compare_exchange(EMPTY, FULL), Ok(EMPTY));
+            })
+        };
+
+        if handoff.state.compare_exchange(FULL, TAKEN).is_ok() {
+            assert_eq!(handoff.data.with(|data| unsafe { *data }), 42);
+        }
+
+        producer.join().unwrap();
+        assert_ne!(handoff.state.load(), EMPTY);
+    });
+}
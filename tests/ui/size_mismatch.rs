@@ -0,0 +1,8 @@
+use subatomic::Subatomic64;
+
+// trybuild only checks the crate, which evaluates the size check in const contexts like this one but not in `new`.
+static VALUE: Subatomic64<u32> = Subatomic64::from_bits(0);
+
+fn main() {
+    VALUE.store(1);
+}